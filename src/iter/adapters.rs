@@ -0,0 +1,641 @@
+use super::naive_datetime_iter::NaiveDatetimeIterator;
+use chrono::{Duration, NaiveDateTime, NaiveTime, Timelike};
+
+impl NaiveDatetimeIterator {
+    /// Pairs each datetime with the one yielded before it
+    ///
+    /// The first datetime is paired with `None`.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono_kit::iter::NaiveDatetimeIterator;
+    /// use chrono::{NaiveDateTime, Duration};
+    ///
+    /// let start = NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let end = NaiveDateTime::parse_from_str("2023-01-02 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    ///
+    /// let mut iter = NaiveDatetimeIterator::new(start, end, Duration::days(1)).unwrap().with_lag();
+    /// assert_eq!(iter.next(), Some((None, start)));
+    /// assert_eq!(iter.next(), Some((Some(start), end)));
+    /// ```
+    pub fn with_lag(self) -> impl Iterator<Item = (Option<NaiveDateTime>, NaiveDateTime)> {
+        let mut prev = None;
+        self.map(move |dt| (prev.replace(dt), dt))
+    }
+
+    /// Pairs each datetime with the time elapsed since its midnight
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono_kit::iter::NaiveDatetimeIterator;
+    /// use chrono::{NaiveDateTime, Duration};
+    ///
+    /// let start = NaiveDateTime::parse_from_str("2023-01-01 18:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let end = NaiveDateTime::parse_from_str("2023-01-02 06:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    ///
+    /// let mut iter = NaiveDatetimeIterator::new(start, end, Duration::hours(6)).unwrap().with_time_of_day_offset();
+    /// assert_eq!(iter.next(), Some((start, Duration::hours(18))));
+    /// assert_eq!(iter.next(), Some((end - Duration::hours(6), Duration::zero())));
+    /// ```
+    pub fn with_time_of_day_offset(self) -> impl Iterator<Item = (NaiveDateTime, Duration)> {
+        self.map(|dt| (dt, dt.time() - NaiveTime::MIN))
+    }
+
+    /// Yields each datetime with its fractional second dropped
+    ///
+    /// Consecutive datetimes may become equal if the step is shorter than a
+    /// second.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono_kit::iter::NaiveDatetimeIterator;
+    /// use chrono::{NaiveDateTime, Duration};
+    ///
+    /// let start = NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let end = start + Duration::seconds(3);
+    ///
+    /// let iter = NaiveDatetimeIterator::new(start, end, Duration::milliseconds(1500)).unwrap();
+    /// let points: Vec<_> = iter.truncate_to_seconds().collect();
+    /// assert_eq!(points, vec![start, start + Duration::seconds(1), end]);
+    /// ```
+    pub fn truncate_to_seconds(self) -> impl Iterator<Item = NaiveDateTime> {
+        self.map(|dt| {
+            dt.with_nanosecond(0)
+                .expect("zero nanoseconds are always valid")
+        })
+    }
+
+    /// Counts the remaining datetimes matching `pred` without collecting them
+    ///
+    /// This is `filter(pred).count()` under a name: `pred` is called once for
+    /// every remaining datetime, since any of them may match. To count all of
+    /// them use [`len`](ExactSizeIterator::len), which does not iterate.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono_kit::iter::NaiveDatetimeIterator;
+    /// use chrono::{Datelike, NaiveDateTime, Duration, Weekday};
+    ///
+    /// let start = NaiveDateTime::parse_from_str("2023-01-02 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let end = NaiveDateTime::parse_from_str("2023-01-08 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    ///
+    /// let iter = NaiveDatetimeIterator::new(start, end, Duration::days(1)).unwrap();
+    /// assert_eq!(iter.count_matching(|dt| dt.weekday() == Weekday::Mon), 1);
+    /// ```
+    pub fn count_matching(self, pred: impl Fn(NaiveDateTime) -> bool) -> usize {
+        self.filter(|dt| pred(*dt)).count()
+    }
+
+    /// Groups the datetimes into maximal runs over which `pred` is constant
+    ///
+    /// Each run is returned as `(first, last, value)`, where `first` and
+    /// `last` are the first and last datetimes of the run in iteration order
+    /// and `value` is what `pred` returned for all of them. A run of a single
+    /// datetime has `first == last`.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono_kit::iter::NaiveDatetimeIterator;
+    /// use chrono::{Datelike, NaiveDateTime, Duration, Weekday};
+    ///
+    /// let friday = NaiveDateTime::parse_from_str("2023-01-06 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let monday = NaiveDateTime::parse_from_str("2023-01-09 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    ///
+    /// let iter = NaiveDatetimeIterator::new(friday, monday, Duration::days(1)).unwrap();
+    /// let runs = iter.runs(|dt| dt.weekday() == Weekday::Sat || dt.weekday() == Weekday::Sun);
+    /// assert_eq!(runs.len(), 3);
+    /// assert_eq!(runs[1], (friday + Duration::days(1), friday + Duration::days(2), true));
+    /// ```
+    pub fn runs(
+        self,
+        pred: impl Fn(NaiveDateTime) -> bool,
+    ) -> Vec<(NaiveDateTime, NaiveDateTime, bool)> {
+        let mut runs: Vec<(NaiveDateTime, NaiveDateTime, bool)> = Vec::new();
+        for dt in self {
+            let value = pred(dt);
+            match runs.last_mut() {
+                Some(run) if run.2 == value => run.1 = dt,
+                _ => runs.push((dt, dt, value)),
+            }
+        }
+        runs
+    }
+
+    /// Pairs each datetime with its bin index on the grid
+    ///
+    /// The index is the datetime's position counted from the grid origin
+    /// (`start`, or `end` for descending steps), not from where iteration
+    /// resumed, so it is unaffected by datetimes already consumed.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono_kit::iter::NaiveDatetimeIterator;
+    /// use chrono::{NaiveDateTime, Duration};
+    ///
+    /// let start = NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let end = NaiveDateTime::parse_from_str("2023-01-05 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    ///
+    /// let mut iter = NaiveDatetimeIterator::new(start, end, Duration::days(1)).unwrap();
+    /// iter.nth(1);
+    /// assert_eq!(iter.bin_indices().next(), Some((start + Duration::days(2), 2)));
+    /// ```
+    pub fn bin_indices(mut self) -> impl Iterator<Item = (NaiveDateTime, usize)> {
+        std::iter::from_fn(move || {
+            let position = self.next_position()?;
+            let index = usize::try_from(position).unwrap_or(usize::MAX);
+            Some((self.point_at(position), index))
+        })
+    }
+
+    /// Pairs each datetime with the signed gap to its nearest sample
+    ///
+    /// `sorted` must be in ascending order. The gap is `sample - datetime`,
+    /// so it is negative when the nearest sample lies before the datetime;
+    /// ties go to the earlier sample. Every datetime is paired with `None`
+    /// when `sorted` is empty. A single cursor sweeps `sorted` alongside the
+    /// grid, so the whole pass is O(n + m) in either step direction.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono_kit::iter::NaiveDatetimeIterator;
+    /// use chrono::{NaiveDateTime, Duration};
+    ///
+    /// let start = NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let end = NaiveDateTime::parse_from_str("2023-01-01 02:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let samples = [start + Duration::minutes(50)];
+    ///
+    /// let gaps: Vec<_> = NaiveDatetimeIterator::new(start, end, Duration::hours(1))
+    ///     .unwrap()
+    ///     .nearest_sample_gap(&samples)
+    ///     .map(|(_, gap)| gap)
+    ///     .collect();
+    /// assert_eq!(
+    ///     gaps,
+    ///     vec![Some(Duration::minutes(50)), Some(Duration::minutes(-10)), Some(Duration::minutes(-70))]
+    /// );
+    /// ```
+    pub fn nearest_sample_gap(
+        self,
+        sorted: &[NaiveDateTime],
+    ) -> impl Iterator<Item = (NaiveDateTime, Option<Duration>)> {
+        // `cursor` is the number of samples strictly before the current datetime
+        let mut cursor = 0;
+        self.map(move |dt| {
+            while cursor < sorted.len() && sorted[cursor] < dt {
+                cursor += 1;
+            }
+            while cursor > 0 && sorted[cursor - 1] >= dt {
+                cursor -= 1;
+            }
+
+            let before = cursor.checked_sub(1).map(|i| sorted[i] - dt);
+            let after = sorted.get(cursor).map(|&sample| sample - dt);
+            let gap = match (before, after) {
+                (Some(b), Some(a)) => Some(if a < -b { a } else { b }),
+                (b, a) => b.or(a),
+            };
+            (dt, gap)
+        })
+    }
+
+    /// Collects the datetimes that have no sample within `tol` of them
+    ///
+    /// `sorted` must be sorted ascending. A sample exactly `tol` away still
+    /// counts as nearby, and the sign of `tol` is ignored. Samples are swept
+    /// with a cursor alongside the datetimes, so the whole pass is linear
+    /// for either step direction.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono_kit::iter::NaiveDatetimeIterator;
+    /// use chrono::{NaiveDateTime, Duration};
+    ///
+    /// let start = NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let end = NaiveDateTime::parse_from_str("2023-01-01 02:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let samples = [start + Duration::seconds(5), end - Duration::seconds(3)];
+    ///
+    /// let missing = NaiveDatetimeIterator::new(start, end, Duration::hours(1))
+    ///     .unwrap()
+    ///     .missing_points(&samples, Duration::seconds(10));
+    /// assert_eq!(missing, vec![start + Duration::hours(1)]);
+    /// ```
+    pub fn missing_points(self, sorted: &[NaiveDateTime], tol: Duration) -> Vec<NaiveDateTime> {
+        let tol = tol.abs();
+        // `cursor` is the number of samples more than `tol` before the current datetime
+        let mut cursor = 0;
+        self.filter(|&dt| {
+            let earliest = dt.checked_sub_signed(tol).unwrap_or(NaiveDateTime::MIN);
+            let latest = dt.checked_add_signed(tol).unwrap_or(NaiveDateTime::MAX);
+            while cursor < sorted.len() && sorted[cursor] < earliest {
+                cursor += 1;
+            }
+            while cursor > 0 && sorted[cursor - 1] >= earliest {
+                cursor -= 1;
+            }
+            sorted.get(cursor).is_none_or(|&sample| sample > latest)
+        })
+        .collect()
+    }
+
+    /// Skips the datetimes falling within any blackout window
+    ///
+    /// Each blackout is half-open, `[start, end)`, so a datetime equal to a
+    /// blackout's end is kept; blackouts whose end is not after their start
+    /// exclude nothing. The blackouts need not be sorted or disjoint: they
+    /// are sorted and merged once up front, after which each datetime is
+    /// checked with a binary search.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono_kit::iter::NaiveDatetimeIterator;
+    /// use chrono::{NaiveDateTime, Duration};
+    ///
+    /// let start = NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let end = NaiveDateTime::parse_from_str("2023-01-01 04:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let hour = Duration::hours(1);
+    /// let maintenance = [(start + hour, start + hour * 3)];
+    ///
+    /// let iter = NaiveDatetimeIterator::new(start, end, hour).unwrap();
+    /// let points: Vec<_> = iter.exclude_intervals(&maintenance).collect();
+    /// assert_eq!(points, vec![start, start + hour * 3, end]);
+    /// ```
+    pub fn exclude_intervals(
+        self,
+        blackouts: &[(NaiveDateTime, NaiveDateTime)],
+    ) -> impl Iterator<Item = NaiveDateTime> {
+        let mut sorted: Vec<_> = blackouts
+            .iter()
+            .copied()
+            .filter(|(start, end)| start < end)
+            .collect();
+        sorted.sort_unstable();
+
+        let mut merged: Vec<(NaiveDateTime, NaiveDateTime)> = Vec::with_capacity(sorted.len());
+        for (start, end) in sorted {
+            match merged.last_mut() {
+                Some(last) if start <= last.1 => last.1 = last.1.max(end),
+                _ => merged.push((start, end)),
+            }
+        }
+
+        self.filter(move |&dt| {
+            let after = merged.partition_point(|&(start, _)| start <= dt);
+            after == 0 || merged[after - 1].1 <= dt
+        })
+    }
+
+    /// Erases the iterator's concrete type while keeping it `Send`
+    ///
+    /// Useful for storing differently configured iterators, or iterators
+    /// wrapped in different adapters, in one collection.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono_kit::iter::NaiveDatetimeIterator;
+    /// use chrono::{NaiveDateTime, Duration};
+    ///
+    /// let start = NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let end = NaiveDateTime::parse_from_str("2023-01-02 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    ///
+    /// let mut iter = NaiveDatetimeIterator::new(start, end, Duration::days(1)).unwrap().boxed();
+    /// assert_eq!(iter.next(), Some(start));
+    /// ```
+    pub fn boxed(self) -> Box<dyn Iterator<Item = NaiveDateTime> + Send> {
+        Box::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Datelike, Duration, Weekday};
+
+    fn dt(s: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    #[test]
+    fn test_with_lag() {
+        let iter = NaiveDatetimeIterator::new(
+            dt("2023-01-01 00:00:00"),
+            dt("2023-01-01 05:00:00"),
+            Duration::hours(-2),
+        )
+        .unwrap();
+
+        let pairs: Vec<_> = iter.with_lag().collect();
+        assert_eq!(
+            pairs,
+            vec![
+                (None, dt("2023-01-01 05:00:00")),
+                (Some(dt("2023-01-01 05:00:00")), dt("2023-01-01 03:00:00")),
+                (Some(dt("2023-01-01 03:00:00")), dt("2023-01-01 01:00:00")),
+                (Some(dt("2023-01-01 01:00:00")), dt("2023-01-01 00:00:00")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_truncate_to_seconds() {
+        let start = dt("2023-01-01 23:59:58") + Duration::milliseconds(250);
+        let end = dt("2023-01-02 00:00:01") + Duration::nanoseconds(999_999_999);
+
+        let iter = NaiveDatetimeIterator::new(start, end, Duration::milliseconds(-900)).unwrap();
+        let points: Vec<_> = iter.truncate_to_seconds().collect();
+        assert_eq!(
+            points,
+            vec![
+                dt("2023-01-02 00:00:01"),
+                dt("2023-01-02 00:00:01"),
+                dt("2023-01-02 00:00:00"),
+                dt("2023-01-01 23:59:59"),
+                dt("2023-01-01 23:59:58"),
+                dt("2023-01-01 23:59:58"),
+            ]
+        );
+        assert!(points.iter().all(|p| p.nanosecond() == 0));
+    }
+
+    #[test]
+    fn test_with_time_of_day_offset() {
+        let iter = NaiveDatetimeIterator::new(
+            dt("2023-01-01 14:30:00"),
+            dt("2023-01-02 00:00:00"),
+            Duration::minutes(570),
+        )
+        .unwrap();
+
+        let offsets: Vec<_> = iter.with_time_of_day_offset().collect();
+        assert_eq!(
+            offsets,
+            vec![
+                (dt("2023-01-01 14:30:00"), Duration::minutes(14 * 60 + 30)),
+                (dt("2023-01-02 00:00:00"), Duration::zero()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_bin_indices_survive_skip() {
+        let start = dt("2023-01-01 00:00:00");
+        let end = dt("2023-01-01 05:00:00");
+
+        let mut iter = NaiveDatetimeIterator::new(start, end, Duration::hours(1)).unwrap();
+        iter.nth(1);
+        let bins: Vec<_> = iter.bin_indices().collect();
+        assert_eq!(bins.first(), Some(&(dt("2023-01-01 02:00:00"), 2)));
+        assert_eq!(bins.last(), Some(&(end, 5)));
+
+        let bins: Vec<_> = NaiveDatetimeIterator::new(start, end, Duration::hours(-2))
+            .unwrap()
+            .bin_indices()
+            .skip(2)
+            .collect();
+        assert_eq!(bins, vec![(dt("2023-01-01 01:00:00"), 2), (start, 3)]);
+    }
+
+    #[test]
+    fn test_boxed_in_collection() {
+        let start = dt("2023-01-01 00:00:00");
+        let end = dt("2023-01-01 06:00:00");
+
+        let iters: Vec<Box<dyn Iterator<Item = NaiveDateTime> + Send>> = vec![
+            NaiveDatetimeIterator::new(start, end, Duration::hours(3))
+                .unwrap()
+                .boxed(),
+            NaiveDatetimeIterator::new(start, end, Duration::hours(-2))
+                .unwrap()
+                .boxed(),
+        ];
+
+        let drained: Vec<Vec<_>> = iters.into_iter().map(Iterator::collect).collect();
+        assert_eq!(drained[0], vec![start, dt("2023-01-01 03:00:00"), end]);
+        assert_eq!(
+            drained[1],
+            vec![
+                end,
+                dt("2023-01-01 04:00:00"),
+                dt("2023-01-01 02:00:00"),
+                start
+            ]
+        );
+    }
+
+    #[test]
+    fn test_nearest_sample_gap() {
+        let samples = [
+            dt("2023-01-01 00:20:00"),
+            dt("2023-01-01 01:50:00"),
+            dt("2023-01-01 02:30:00"),
+        ];
+        let start = dt("2023-01-01 00:00:00");
+        let end = dt("2023-01-01 03:00:00");
+
+        let gaps: Vec<_> = NaiveDatetimeIterator::new(start, end, Duration::hours(1))
+            .unwrap()
+            .nearest_sample_gap(&samples)
+            .collect();
+        assert_eq!(
+            gaps,
+            vec![
+                (start, Some(Duration::minutes(20))),
+                (dt("2023-01-01 01:00:00"), Some(Duration::minutes(-40))),
+                (dt("2023-01-01 02:00:00"), Some(Duration::minutes(-10))),
+                (end, Some(Duration::minutes(-30))),
+            ]
+        );
+
+        let gaps: Vec<_> = NaiveDatetimeIterator::new(start, end, Duration::hours(-1))
+            .unwrap()
+            .nearest_sample_gap(&samples)
+            .map(|(_, gap)| gap.unwrap().num_minutes())
+            .collect();
+        assert_eq!(gaps, vec![-30, -10, -40, 20]);
+    }
+
+    #[test]
+    fn test_nearest_sample_gap_ties_and_exact_hits() {
+        let samples = [dt("2023-01-01 00:30:00"), dt("2023-01-01 01:30:00")];
+
+        let gaps: Vec<_> = NaiveDatetimeIterator::new(
+            dt("2023-01-01 00:30:00"),
+            dt("2023-01-01 01:00:00"),
+            Duration::minutes(30),
+        )
+        .unwrap()
+        .nearest_sample_gap(&samples)
+        .map(|(_, gap)| gap)
+        .collect();
+        assert_eq!(
+            gaps,
+            vec![Some(Duration::zero()), Some(Duration::minutes(-30))]
+        );
+    }
+
+    #[test]
+    fn test_nearest_sample_gap_no_samples() {
+        let iter = NaiveDatetimeIterator::new(
+            dt("2023-01-01 00:00:00"),
+            dt("2023-01-01 02:00:00"),
+            Duration::hours(1),
+        )
+        .unwrap();
+
+        assert!(iter.nearest_sample_gap(&[]).all(|(_, gap)| gap.is_none()));
+    }
+
+    #[test]
+    fn test_missing_points() {
+        let samples = [
+            dt("2023-01-01 00:00:30"),
+            dt("2023-01-01 00:59:00"),
+            dt("2023-01-01 03:01:00"),
+        ];
+        let start = dt("2023-01-01 00:00:00");
+        let end = dt("2023-01-01 03:00:00");
+        let tol = Duration::minutes(1);
+
+        let missing = NaiveDatetimeIterator::new(start, end, Duration::hours(1))
+            .unwrap()
+            .missing_points(&samples, tol);
+        assert_eq!(missing, vec![dt("2023-01-01 02:00:00")]);
+
+        let missing = NaiveDatetimeIterator::new(start, end, Duration::hours(-1))
+            .unwrap()
+            .missing_points(&samples, -tol);
+        assert_eq!(missing, vec![dt("2023-01-01 02:00:00")]);
+
+        let missing = NaiveDatetimeIterator::new(start, end, Duration::hours(1))
+            .unwrap()
+            .missing_points(&samples, Duration::seconds(59));
+        assert_eq!(
+            missing,
+            vec![dt("2023-01-01 01:00:00"), dt("2023-01-01 02:00:00"), end]
+        );
+    }
+
+    #[test]
+    fn test_missing_points_no_samples() {
+        let start = dt("2023-01-01 00:00:00");
+        let end = dt("2023-01-01 02:00:00");
+
+        let missing = NaiveDatetimeIterator::new(start, end, Duration::hours(1))
+            .unwrap()
+            .missing_points(&[], Duration::hours(1));
+        assert_eq!(missing.len(), 3);
+    }
+
+    #[test]
+    fn test_exclude_intervals() {
+        let blackouts = [
+            (dt("2023-01-01 05:00:00"), dt("2023-01-01 06:00:00")),
+            (dt("2023-01-01 01:30:00"), dt("2023-01-01 03:00:00")),
+            (dt("2023-01-01 02:00:00"), dt("2023-01-01 02:30:00")),
+            (dt("2023-01-01 04:00:00"), dt("2023-01-01 04:00:00")),
+        ];
+        let start = dt("2023-01-01 00:00:00");
+        let end = dt("2023-01-01 06:00:00");
+
+        let expected = vec![
+            dt("2023-01-01 00:00:00"),
+            dt("2023-01-01 01:00:00"),
+            dt("2023-01-01 03:00:00"),
+            dt("2023-01-01 04:00:00"),
+            dt("2023-01-01 06:00:00"),
+        ];
+        let points: Vec<_> = NaiveDatetimeIterator::new(start, end, Duration::hours(1))
+            .unwrap()
+            .exclude_intervals(&blackouts)
+            .collect();
+        assert_eq!(points, expected);
+
+        let mut points: Vec<_> = NaiveDatetimeIterator::new(start, end, Duration::hours(-1))
+            .unwrap()
+            .exclude_intervals(&blackouts)
+            .collect();
+        points.reverse();
+        assert_eq!(points, expected);
+    }
+
+    #[test]
+    fn test_exclude_intervals_no_blackouts() {
+        let iter = NaiveDatetimeIterator::new(
+            dt("2023-01-01 00:00:00"),
+            dt("2023-01-01 02:00:00"),
+            Duration::hours(1),
+        )
+        .unwrap();
+        assert_eq!(iter.exclude_intervals(&[]).count(), 3);
+    }
+
+    #[test]
+    fn test_count_matching_weekends() {
+        let is_weekend = |dt: NaiveDateTime| matches!(dt.weekday(), Weekday::Sat | Weekday::Sun);
+
+        let daily = NaiveDatetimeIterator::new(
+            dt("2023-01-02 00:00:00"),
+            dt("2023-01-15 00:00:00"),
+            Duration::days(1),
+        )
+        .unwrap();
+        assert_eq!(daily.count_matching(is_weekend), 4);
+
+        let hourly = NaiveDatetimeIterator::new(
+            dt("2023-01-02 00:00:00"),
+            dt("2023-01-15 23:00:00"),
+            Duration::hours(1),
+        )
+        .unwrap();
+        assert_eq!(hourly.count_matching(is_weekend), 4 * 24);
+    }
+
+    #[test]
+    fn test_runs_over_weekend() {
+        let is_weekend = |dt: NaiveDateTime| matches!(dt.weekday(), Weekday::Sat | Weekday::Sun);
+        let iter = NaiveDatetimeIterator::new(
+            dt("2023-01-04 00:00:00"),
+            dt("2023-01-10 00:00:00"),
+            Duration::days(1),
+        )
+        .unwrap();
+
+        assert_eq!(
+            iter.runs(is_weekend),
+            vec![
+                (dt("2023-01-04 00:00:00"), dt("2023-01-06 00:00:00"), false),
+                (dt("2023-01-07 00:00:00"), dt("2023-01-08 00:00:00"), true),
+                (dt("2023-01-09 00:00:00"), dt("2023-01-10 00:00:00"), false),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_runs_descending_and_single_points() {
+        let iter = NaiveDatetimeIterator::new(
+            dt("2023-01-01 00:00:00"),
+            dt("2023-01-01 04:00:00"),
+            Duration::hours(-1),
+        )
+        .unwrap();
+
+        let runs = iter.runs(|dt| dt.hour() % 2 == 0);
+        assert_eq!(runs.len(), 5);
+        assert!(runs.iter().all(|(first, last, _)| first == last));
+        assert_eq!(
+            runs[0],
+            (dt("2023-01-01 04:00:00"), dt("2023-01-01 04:00:00"), true)
+        );
+
+        let runs = NaiveDatetimeIterator::new(
+            dt("2023-01-01 00:00:00"),
+            dt("2023-01-01 04:00:00"),
+            Duration::hours(-1),
+        )
+        .unwrap()
+        .runs(|_| true);
+        assert_eq!(
+            runs,
+            vec![(dt("2023-01-01 04:00:00"), dt("2023-01-01 00:00:00"), true)]
+        );
+    }
+}
@@ -0,0 +1,643 @@
+use super::naive_datetime_iter::{NaiveDatetimeIterError, NaiveDatetimeIterator};
+use super::naive_datetime_range_iter::NaiveDatetimeRangeIterator;
+use super::util::{duration_to_nanos, epoch, nanos_to_duration};
+use chrono::{Duration, NaiveDateTime};
+use std::cmp::Reverse;
+
+/// Returns the first grid point at or after `start`
+///
+/// The grid is anchored at the Unix epoch, shifted by `phase`, and repeats
+/// every `step`, i.e. it contains every `1970-01-01 00:00:00 + phase + k * step`
+/// for integer `k`. The sign of `step` is ignored and `phase` may be larger
+/// than `step` or negative.
+///
+/// # Panics
+/// Panics if `step` is zero or the aligned datetime is out of range.
+///
+/// # Examples
+/// ```
+/// use chrono_kit::iter::aligned_start;
+/// use chrono::{NaiveDateTime, Duration};
+///
+/// let start = NaiveDateTime::parse_from_str("2023-01-01 09:17:00", "%Y-%m-%d %H:%M:%S").unwrap();
+/// let aligned = aligned_start(start, Duration::hours(1), Duration::minutes(30));
+/// assert_eq!(aligned.to_string(), "2023-01-01 09:30:00");
+/// ```
+pub fn aligned_start(start: NaiveDateTime, step: Duration, phase: Duration) -> NaiveDateTime {
+    assert!(!step.is_zero(), "step duration cannot be zero");
+
+    let step = duration_to_nanos(step).abs();
+    let offset = (duration_to_nanos(start - epoch()) - duration_to_nanos(phase)).rem_euclid(step);
+    if offset == 0 {
+        return start;
+    }
+
+    nanos_to_duration(step - offset)
+        .and_then(|delta| start.checked_add_signed(delta))
+        .expect("aligned datetime out of range")
+}
+
+/// Returns the first point of the grid `start + k * step` strictly after `now`
+///
+/// The grid runs forward from `start` without an end, so if `now` is before
+/// `start` the answer is `start` itself. The sign of `step` is ignored. The
+/// point is computed directly rather than by stepping, so `now` may be
+/// arbitrarily far from `start`.
+///
+/// Returns `None` if `step` is zero or the next grid point is out of range.
+///
+/// # Examples
+/// ```
+/// use chrono_kit::iter::next_grid_point;
+/// use chrono::{NaiveDateTime, Duration};
+///
+/// let start = NaiveDateTime::parse_from_str("2023-01-01 09:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+/// let now = NaiveDateTime::parse_from_str("2023-03-15 14:07:00", "%Y-%m-%d %H:%M:%S").unwrap();
+///
+/// let next = next_grid_point(start, Duration::minutes(30), now).unwrap();
+/// assert_eq!(next.to_string(), "2023-03-15 14:30:00");
+/// ```
+pub fn next_grid_point(
+    start: NaiveDateTime,
+    step: Duration,
+    now: NaiveDateTime,
+) -> Option<NaiveDateTime> {
+    if step.is_zero() {
+        return None;
+    }
+    if now < start {
+        return Some(start);
+    }
+
+    let step = duration_to_nanos(step).abs();
+    let steps = duration_to_nanos(now - start) / step + 1;
+    nanos_to_duration(steps * step).and_then(|offset| start.checked_add_signed(offset))
+}
+
+/// Number of bin counts on either side of `span / target` that [`snap_step`] tries
+const SNAP_SEARCH_WINDOW: u128 = 1_000;
+
+/// Returns the step nearest to `target` that divides `[start, end]` into whole bins
+///
+/// The result is `(end - start) / n` for the whole number of bins `n` that
+/// brings it closest to `target`, with the larger step winning a tie, so a
+/// grid built with it ends exactly on `end` without a clamped tail. Only bin
+/// counts within 1000 of `(end - start) / target` are tried, so the search is
+/// cheap even when the span has few divisors. A single bin always divides the
+/// span, so if none of those counts does, for example when the span is a
+/// prime number of nanoseconds, the whole span is returned. The signs of
+/// `target` and of the span are ignored, and if `start == end` any step fits,
+/// so `target` itself is returned.
+///
+/// # Panics
+/// Panics if `target` is zero.
+///
+/// # Examples
+/// ```
+/// use chrono_kit::iter::snap_step;
+/// use chrono::{NaiveDateTime, Duration};
+///
+/// let start = NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+/// let end = NaiveDateTime::parse_from_str("2023-01-01 01:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+///
+/// assert_eq!(snap_step(start, end, Duration::minutes(25)), Duration::minutes(30));
+/// assert_eq!(snap_step(start, end, Duration::minutes(7)), Duration::seconds(400));
+/// assert_eq!(snap_step(start, end, Duration::hours(2)), Duration::hours(1));
+/// ```
+pub fn snap_step(start: NaiveDateTime, end: NaiveDateTime, target: Duration) -> Duration {
+    assert!(!target.is_zero(), "step duration cannot be zero");
+    let span = duration_to_nanos(end - start).unsigned_abs();
+    if span == 0 {
+        return target.abs();
+    }
+
+    let target = duration_to_nanos(target).unsigned_abs();
+    let nearest = span / target;
+    let counts = nearest.saturating_sub(SNAP_SEARCH_WINDOW).max(1)
+        ..=nearest.saturating_add(SNAP_SEARCH_WINDOW).min(span);
+    let best = counts
+        .chain([1])
+        .filter(|n| span.is_multiple_of(*n))
+        .map(|n| span / n)
+        .min_by_key(|step| (step.abs_diff(target), Reverse(*step)))
+        .unwrap_or(span);
+    // The span of two datetimes always fits in a `Duration`
+    nanos_to_duration(best as i128).unwrap()
+}
+
+/// Yields 15-minute ranges aligned to `:00`, `:15`, `:30`, and `:45`
+///
+/// The bounds are snapped to quarter-hour boundaries regardless of their own
+/// minutes. With `include_partial`, the slots containing `start` and `end`
+/// are included, so the slots cover the whole of `[start, end]`. Without it,
+/// only slots lying entirely within `[start, end]` are yielded, which may be
+/// none at all.
+///
+/// # Errors
+/// Returns `NaiveDatetimeIterError::InvalidRange` if `start` is after `end`.
+///
+/// # Panics
+/// Panics if a snapped bound is out of range.
+///
+/// # Examples
+/// ```
+/// use chrono_kit::iter::quarter_hour_slots;
+/// use chrono::NaiveDateTime;
+///
+/// let start = NaiveDateTime::parse_from_str("2023-01-01 09:07:00", "%Y-%m-%d %H:%M:%S").unwrap();
+/// let end = NaiveDateTime::parse_from_str("2023-01-01 09:50:00", "%Y-%m-%d %H:%M:%S").unwrap();
+///
+/// let slots: Vec<_> = quarter_hour_slots(start, end, false).unwrap().collect();
+/// assert_eq!(slots.len(), 2);
+/// assert_eq!(slots[0].0.to_string(), "2023-01-01 09:15:00");
+/// assert_eq!(slots[1].1.to_string(), "2023-01-01 09:45:00");
+/// ```
+pub fn quarter_hour_slots(
+    start: NaiveDateTime,
+    end: NaiveDateTime,
+    include_partial: bool,
+) -> Result<NaiveDatetimeRangeIterator, NaiveDatetimeIterError> {
+    if start > end {
+        return Err(NaiveDatetimeIterError::InvalidRange { start, end });
+    }
+
+    let slot = Duration::minutes(15);
+    let ceil = |dt| aligned_start(dt, slot, Duration::zero());
+    let floor = |dt| {
+        let up = ceil(dt);
+        if up == dt { dt } else { up - slot }
+    };
+    let (first, last) = if include_partial {
+        (floor(start), ceil(end))
+    } else {
+        (ceil(start), floor(end))
+    };
+    // Bounds within a single slot snap past each other when partial slots are excluded
+    NaiveDatetimeRangeIterator::new_strict_aligned(first, last.max(first), slot)
+}
+
+/// Direction in which a datetime is snapped onto a step grid
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rounding {
+    /// Snap to the grid point at or before the datetime
+    Floor,
+    /// Snap to the grid point at or after the datetime
+    Ceil,
+    /// Snap to the closer grid point, preferring the later one on a tie
+    Nearest,
+}
+
+impl NaiveDatetimeIterator {
+    /// Creates a new DatetimeIterator whose start is snapped onto a step grid
+    ///
+    /// The grid contains every `origin + k * step` for integer `k`, and `start`
+    /// is moved onto it in the direction given by `rounding`, so the iterator
+    /// may begin before the requested `start`. `end` is left as it is. The
+    /// range is validated after snapping: with [`Rounding::Floor`] a `start`
+    /// just past `end` is accepted if it snaps back to or before `end`, while
+    /// with [`Rounding::Ceil`] a `start` before `end` is rejected if it snaps
+    /// past it. The sign of `step` only sets the direction of iteration.
+    ///
+    /// # Arguments
+    /// * `start` - The datetime to snap onto the grid
+    /// * `end` - The ending datetime (inclusive)
+    /// * `step` - The duration between each step (must be non-zero)
+    /// * `origin` - Any datetime on the grid
+    /// * `rounding` - The direction in which `start` is snapped
+    ///
+    /// # Errors
+    /// Returns `DatetimeIterError` if:
+    /// - `step` is zero
+    /// - the snapped start is out of range (`SnapOutOfRange`)
+    /// - the snapped start is after `end`
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono_kit::iter::{NaiveDatetimeIterator, Rounding};
+    /// use chrono::{NaiveDateTime, Duration};
+    ///
+    /// let start = NaiveDateTime::parse_from_str("2023-01-01 09:17:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let end = NaiveDateTime::parse_from_str("2023-01-01 12:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let origin = NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    ///
+    /// let mut iter = NaiveDatetimeIterator::aligned(start, end, Duration::hours(1), origin, Rounding::Floor).unwrap();
+    /// assert_eq!(iter.next().unwrap().to_string(), "2023-01-01 09:00:00");
+    /// ```
+    pub fn aligned(
+        start: NaiveDateTime,
+        end: NaiveDateTime,
+        step: Duration,
+        origin: NaiveDateTime,
+        rounding: Rounding,
+    ) -> Result<Self, NaiveDatetimeIterError> {
+        if step.is_zero() {
+            return Err(NaiveDatetimeIterError::ZeroStep);
+        }
+
+        let step_nanos = duration_to_nanos(step).abs();
+        let offset = duration_to_nanos(start - origin).rem_euclid(step_nanos);
+        let round_up = match rounding {
+            Rounding::Floor => false,
+            Rounding::Ceil => offset != 0,
+            Rounding::Nearest => offset * 2 >= step_nanos,
+        };
+        let delta = if round_up {
+            step_nanos - offset
+        } else {
+            -offset
+        };
+        let snapped = nanos_to_duration(delta)
+            .and_then(|delta| start.checked_add_signed(delta))
+            .ok_or(NaiveDatetimeIterError::SnapOutOfRange { start })?;
+        Self::new(snapped, end, step)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dt(s: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    #[test]
+    fn test_already_aligned() {
+        let start = dt("2023-01-01 09:00:00");
+        assert_eq!(
+            aligned_start(start, Duration::hours(1), Duration::zero()),
+            start
+        );
+        assert_eq!(
+            aligned_start(start, Duration::days(1), Duration::hours(9)),
+            start
+        );
+    }
+
+    #[test]
+    fn test_rounds_up_to_next_grid_point() {
+        let start = dt("2023-01-01 09:17:00");
+        assert_eq!(
+            aligned_start(start, Duration::minutes(15), Duration::zero()),
+            dt("2023-01-01 09:30:00")
+        );
+        assert_eq!(
+            aligned_start(start, Duration::days(1), Duration::zero()),
+            dt("2023-01-02 00:00:00")
+        );
+        assert_eq!(
+            aligned_start(start, Duration::hours(-1), Duration::zero()),
+            dt("2023-01-01 10:00:00")
+        );
+    }
+
+    #[test]
+    fn test_phase_shift() {
+        let start = dt("2023-01-01 09:17:00");
+        assert_eq!(
+            aligned_start(start, Duration::hours(1), Duration::minutes(20)),
+            dt("2023-01-01 09:20:00")
+        );
+        assert_eq!(
+            aligned_start(start, Duration::hours(1), Duration::minutes(-50)),
+            dt("2023-01-01 10:10:00")
+        );
+    }
+
+    #[test]
+    fn test_phase_larger_than_step() {
+        let start = dt("2023-01-01 09:17:00");
+        assert_eq!(
+            aligned_start(start, Duration::hours(1), Duration::minutes(140)),
+            aligned_start(start, Duration::hours(1), Duration::minutes(20))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "step duration cannot be zero")]
+    fn test_zero_step_panics() {
+        aligned_start(
+            dt("2023-01-01 09:17:00"),
+            Duration::zero(),
+            Duration::zero(),
+        );
+    }
+
+    #[test]
+    fn test_quarter_hour_slots_mid_slot_start() {
+        let start = dt("2023-01-01 09:07:00");
+        let end = dt("2023-01-01 10:00:00");
+
+        let partial: Vec<_> = quarter_hour_slots(start, end, true).unwrap().collect();
+        assert_eq!(partial.len(), 4);
+        assert_eq!(
+            partial[0],
+            (dt("2023-01-01 09:00:00"), dt("2023-01-01 09:15:00"))
+        );
+
+        let contained: Vec<_> = quarter_hour_slots(start, end, false).unwrap().collect();
+        assert_eq!(contained.len(), 3);
+        assert_eq!(
+            contained[0],
+            (dt("2023-01-01 09:15:00"), dt("2023-01-01 09:30:00"))
+        );
+        assert_eq!(contained[2].1, end);
+    }
+
+    #[test]
+    fn test_quarter_hour_slots_mid_slot_end() {
+        let start = dt("2023-01-01 09:00:00");
+        let end = dt("2023-01-01 09:40:00");
+
+        let partial: Vec<_> = quarter_hour_slots(start, end, true).unwrap().collect();
+        assert_eq!(
+            partial.last().map(|slot| slot.1),
+            Some(dt("2023-01-01 09:45:00"))
+        );
+
+        let contained: Vec<_> = quarter_hour_slots(start, end, false).unwrap().collect();
+        assert_eq!(
+            contained.last().map(|slot| slot.1),
+            Some(dt("2023-01-01 09:30:00"))
+        );
+    }
+
+    #[test]
+    fn test_quarter_hour_slots_within_one_slot() {
+        let start = dt("2023-01-01 09:03:00");
+        let end = dt("2023-01-01 09:12:00");
+
+        let partial: Vec<_> = quarter_hour_slots(start, end, true).unwrap().collect();
+        assert_eq!(
+            partial,
+            vec![(dt("2023-01-01 09:00:00"), dt("2023-01-01 09:15:00"))]
+        );
+        assert_eq!(quarter_hour_slots(start, end, false).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn test_quarter_hour_slots_invalid_range() {
+        let result = quarter_hour_slots(dt("2023-01-01 10:00:00"), dt("2023-01-01 09:00:00"), true);
+        assert!(matches!(
+            result,
+            Err(NaiveDatetimeIterError::InvalidRange { .. })
+        ));
+    }
+
+    #[test]
+    fn test_next_grid_point_on_and_between_points() {
+        let start = dt("2023-01-01 09:00:00");
+        let step = Duration::minutes(15);
+
+        assert_eq!(
+            next_grid_point(start, step, dt("2023-01-01 09:45:00")),
+            Some(dt("2023-01-01 10:00:00"))
+        );
+        assert_eq!(
+            next_grid_point(start, step, dt("2023-01-01 09:44:59")),
+            Some(dt("2023-01-01 09:45:00"))
+        );
+        assert_eq!(
+            next_grid_point(start, step, start),
+            Some(dt("2023-01-01 09:15:00"))
+        );
+        assert_eq!(
+            next_grid_point(start, -step, dt("2024-06-30 23:59:00")),
+            Some(dt("2024-07-01 00:00:00"))
+        );
+    }
+
+    #[test]
+    fn test_next_grid_point_before_start() {
+        let start = dt("2023-01-01 09:00:00");
+        assert_eq!(
+            next_grid_point(start, Duration::hours(1), dt("2022-01-01 00:00:00")),
+            Some(start)
+        );
+    }
+
+    #[test]
+    fn test_next_grid_point_out_of_range() {
+        assert_eq!(
+            next_grid_point(
+                NaiveDateTime::MIN,
+                Duration::days(100_000),
+                NaiveDateTime::MAX - Duration::days(1),
+            ),
+            None
+        );
+        assert_eq!(
+            next_grid_point(
+                NaiveDateTime::MAX - Duration::hours(1),
+                Duration::hours(1),
+                NaiveDateTime::MAX,
+            ),
+            None
+        );
+        let start = dt("2023-01-01 09:00:00");
+        assert_eq!(next_grid_point(start, Duration::zero(), start), None);
+    }
+
+    #[test]
+    fn test_aligned_to_hour() {
+        let origin = dt("2023-01-01 00:00:00");
+        let start = dt("2023-01-01 09:17:00");
+        let end = dt("2023-01-01 12:00:00");
+        let hour = Duration::hours(1);
+        let first = |rounding| {
+            NaiveDatetimeIterator::aligned(start, end, hour, origin, rounding)
+                .unwrap()
+                .next()
+                .unwrap()
+        };
+
+        assert_eq!(first(Rounding::Floor), dt("2023-01-01 09:00:00"));
+        assert_eq!(first(Rounding::Ceil), dt("2023-01-01 10:00:00"));
+        assert_eq!(first(Rounding::Nearest), dt("2023-01-01 09:00:00"));
+
+        let points: Vec<_> =
+            NaiveDatetimeIterator::aligned(start, end, hour, origin, Rounding::Floor)
+                .unwrap()
+                .collect();
+        assert_eq!(points.len(), 4);
+        assert_eq!(points[3], end);
+    }
+
+    #[test]
+    fn test_aligned_to_day_with_offset_origin() {
+        let origin = dt("2000-01-01 06:00:00");
+        let start = dt("2023-03-10 19:30:00");
+        let end = dt("2023-03-13 06:00:00");
+        let day = Duration::days(1);
+
+        let points: Vec<_> =
+            NaiveDatetimeIterator::aligned(start, end, day, origin, Rounding::Nearest)
+                .unwrap()
+                .collect();
+        assert_eq!(
+            points,
+            vec![
+                dt("2023-03-11 06:00:00"),
+                dt("2023-03-12 06:00:00"),
+                dt("2023-03-13 06:00:00"),
+            ]
+        );
+
+        let descending: Vec<_> =
+            NaiveDatetimeIterator::aligned(start, end, -day, origin, Rounding::Floor)
+                .unwrap()
+                .collect();
+        assert_eq!(descending.last(), Some(&dt("2023-03-10 06:00:00")));
+    }
+
+    #[test]
+    fn test_aligned_validates_snapped_start() {
+        let origin = dt("2023-01-01 00:00:00");
+        let hour = Duration::hours(1);
+
+        let past_end = NaiveDatetimeIterator::aligned(
+            dt("2023-01-01 09:17:00"),
+            dt("2023-01-01 09:10:00"),
+            hour,
+            origin,
+            Rounding::Floor,
+        );
+        assert_eq!(past_end.unwrap().next(), Some(dt("2023-01-01 09:00:00")));
+
+        let snapped_past_end = NaiveDatetimeIterator::aligned(
+            dt("2023-01-01 09:17:00"),
+            dt("2023-01-01 09:30:00"),
+            hour,
+            origin,
+            Rounding::Ceil,
+        );
+        assert!(matches!(
+            snapped_past_end,
+            Err(NaiveDatetimeIterError::InvalidRange { .. })
+        ));
+        assert!(matches!(
+            NaiveDatetimeIterator::aligned(
+                origin,
+                origin,
+                Duration::zero(),
+                origin,
+                Rounding::Ceil
+            ),
+            Err(NaiveDatetimeIterError::ZeroStep)
+        ));
+    }
+
+    #[test]
+    fn test_snap_step_even_division() {
+        let start = dt("2023-01-01 00:00:00");
+        let end = dt("2023-01-02 00:00:00");
+        assert_eq!(
+            snap_step(start, end, Duration::hours(2)),
+            Duration::hours(2)
+        );
+        assert_eq!(
+            snap_step(end, start, Duration::hours(-2)),
+            Duration::hours(2)
+        );
+        assert_eq!(
+            snap_step(start, start, Duration::hours(-2)),
+            Duration::hours(2)
+        );
+    }
+
+    #[test]
+    fn test_snap_step_rounds_to_nearest_divisor() {
+        let start = dt("2023-01-01 00:00:00");
+        let end = dt("2023-01-01 00:00:10");
+
+        // 10s does not split into 3 whole-nanosecond bins, so 4 bins of 2.5s win
+        assert_eq!(
+            snap_step(start, end, Duration::seconds(3)),
+            Duration::milliseconds(2_500)
+        );
+        assert_eq!(
+            snap_step(start, end, Duration::milliseconds(4_200)),
+            Duration::seconds(5)
+        );
+
+        let end = dt("2023-01-01 07:00:00");
+        let step = snap_step(start, end, Duration::minutes(50));
+        assert_eq!(step, Duration::minutes(52) + Duration::milliseconds(30_000));
+        let points: Vec<_> = NaiveDatetimeIterator::new(start, end, step)
+            .unwrap()
+            .collect();
+        assert_eq!(points.len(), 9);
+        assert_eq!(points[7] + step, end);
+    }
+
+    #[test]
+    fn test_snap_step_target_longer_than_span() {
+        let start = dt("2023-01-01 00:00:00");
+        let end = dt("2023-01-01 00:00:10");
+        assert_eq!(
+            snap_step(start, end, Duration::hours(1)),
+            Duration::seconds(10)
+        );
+        assert_eq!(
+            snap_step(end, start, Duration::seconds(-11)),
+            Duration::seconds(10)
+        );
+    }
+
+    #[test]
+    fn test_snap_step_prefers_larger_on_tie() {
+        let start = dt("2023-01-01 00:00:00");
+        let end = start + Duration::nanoseconds(12);
+        assert_eq!(
+            snap_step(start, end, Duration::nanoseconds(5)),
+            Duration::nanoseconds(6)
+        );
+    }
+
+    #[test]
+    fn test_snap_step_large_prime_factor() {
+        let start = dt("2023-01-01 00:00:00");
+
+        // 10s + 19ns is prime, and a day plus 2ns is 2 * 13 * 67 * 49598163031
+        let end = start + Duration::seconds(10) + Duration::nanoseconds(19);
+        assert_eq!(snap_step(start, end, Duration::seconds(1)), end - start);
+        let end = start + Duration::days(1) + Duration::nanoseconds(2);
+        assert_eq!(snap_step(start, end, Duration::seconds(1)), end - start);
+        assert_eq!(
+            snap_step(start, end, Duration::hours(12)),
+            Duration::hours(12) + Duration::nanoseconds(1)
+        );
+    }
+
+    #[test]
+    fn test_aligned_snapped_out_of_range() {
+        let origin = dt("2023-01-01 00:00:00");
+        let day = Duration::days(1);
+
+        for rounding in [Rounding::Ceil, Rounding::Nearest] {
+            let result = NaiveDatetimeIterator::aligned(
+                NaiveDateTime::MAX,
+                NaiveDateTime::MAX,
+                day,
+                origin,
+                rounding,
+            );
+            assert!(matches!(
+                result,
+                Err(NaiveDatetimeIterError::SnapOutOfRange { start }) if start == NaiveDateTime::MAX
+            ));
+        }
+
+        let result = NaiveDatetimeIterator::aligned(
+            NaiveDateTime::MIN + Duration::hours(1),
+            NaiveDateTime::MIN + day,
+            day,
+            origin + Duration::hours(12),
+            Rounding::Floor,
+        );
+        assert!(matches!(
+            result,
+            Err(NaiveDatetimeIterError::SnapOutOfRange { .. })
+        ));
+    }
+}
@@ -0,0 +1,797 @@
+use super::month_iter::NaiveDateIterError;
+use super::naive_date_iter::midnight;
+use super::naive_datetime_iter::NaiveDatetimeIterator;
+use chrono::{Datelike, NaiveDate, NaiveDateTime, Weekday};
+use std::collections::HashSet;
+
+/// Returns `true` if `date` is a weekday that `holidays` does not mark as a holiday
+fn is_business_day(date: NaiveDate, holidays: &impl HolidayCalendar) -> bool {
+    !matches!(date.weekday(), Weekday::Sat | Weekday::Sun) && !holidays.is_holiday(date)
+}
+
+/// Moves `n` business days away from `start`, skipping weekends and holidays
+///
+/// A positive `n` moves forward and a negative `n` moves backward. `start`
+/// itself is not counted, so it need not be a business day; with `n == 0`
+/// it is returned unchanged.
+///
+/// # Panics
+/// Panics if the result is outside the range of `NaiveDate`.
+///
+/// # Examples
+/// ```
+/// use chrono_kit::iter::add_business_days;
+/// use chrono::NaiveDate;
+/// use std::collections::HashSet;
+///
+/// let friday = NaiveDate::from_ymd_opt(2023, 1, 6).unwrap();
+/// let tuesday = NaiveDate::from_ymd_opt(2023, 1, 10).unwrap();
+/// assert_eq!(add_business_days(friday, 2, &HashSet::new()), tuesday);
+/// ```
+pub fn add_business_days(start: NaiveDate, n: i64, holidays: &HashSet<NaiveDate>) -> NaiveDate {
+    let mut date = start;
+    for _ in 0..n.unsigned_abs() {
+        loop {
+            date = if n > 0 {
+                date.succ_opt()
+            } else {
+                date.pred_opt()
+            }
+            .expect("business day out of range");
+            if is_business_day(date, holidays) {
+                break;
+            }
+        }
+    }
+    date
+}
+
+/// Yields every `n`-th business day from `start` through `end`
+///
+/// The first business day on or after `start` is always yielded, and each
+/// following date is `n` business days after the previous one, so weekends
+/// and dates in `holidays` never count towards the spacing. Nothing is
+/// yielded if `end` is before `start`.
+///
+/// # Panics
+/// Panics if `n` is zero.
+///
+/// # Examples
+/// ```
+/// use chrono_kit::iter::every_nth_business_day;
+/// use chrono::NaiveDate;
+/// use std::collections::HashSet;
+///
+/// let start = NaiveDate::from_ymd_opt(2023, 1, 2).unwrap();
+/// let end = NaiveDate::from_ymd_opt(2023, 1, 31).unwrap();
+///
+/// let days: Vec<_> = every_nth_business_day(start, end, 10, &HashSet::new()).collect();
+/// assert_eq!(
+///     days,
+///     vec![start, NaiveDate::from_ymd_opt(2023, 1, 16).unwrap(), NaiveDate::from_ymd_opt(2023, 1, 30).unwrap()]
+/// );
+/// ```
+pub fn every_nth_business_day(
+    start: NaiveDate,
+    end: NaiveDate,
+    n: usize,
+    holidays: &HashSet<NaiveDate>,
+) -> impl Iterator<Item = NaiveDate> {
+    start
+        .iter_days()
+        .take_while(move |&date| date <= end)
+        .filter(move |&date| is_business_day(date, holidays))
+        .step_by(n)
+}
+
+/// A source of holidays to skip when walking business days
+///
+/// Implemented for `HashSet<NaiveDate>`, for references to any calendar, and
+/// for `()`, the empty calendar with no holidays.
+///
+/// # Examples
+/// ```
+/// use chrono_kit::iter::HolidayCalendar;
+/// use chrono::{Datelike, NaiveDate};
+///
+/// struct NewYear;
+///
+/// impl HolidayCalendar for NewYear {
+///     fn is_holiday(&self, date: NaiveDate) -> bool {
+///         date.month() == 1 && date.day() == 1
+///     }
+/// }
+///
+/// assert!(NewYear.is_holiday(NaiveDate::from_ymd_opt(2024, 1, 1).unwrap()));
+/// ```
+pub trait HolidayCalendar {
+    /// Returns `true` if `date` is a holiday
+    fn is_holiday(&self, date: NaiveDate) -> bool;
+
+    /// Counts the holidays from `start` to `end` inclusive that `counts` accepts
+    ///
+    /// The default checks every date in the range. Calendars that can list
+    /// their holidays should override it so that counting stays cheap.
+    fn count_holidays(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+        counts: &dyn Fn(NaiveDate) -> bool,
+    ) -> usize {
+        start
+            .iter_days()
+            .take_while(|date| *date <= end)
+            .filter(|date| self.is_holiday(*date) && counts(*date))
+            .count()
+    }
+}
+
+impl HolidayCalendar for () {
+    fn is_holiday(&self, _date: NaiveDate) -> bool {
+        false
+    }
+
+    fn count_holidays(
+        &self,
+        _start: NaiveDate,
+        _end: NaiveDate,
+        _counts: &dyn Fn(NaiveDate) -> bool,
+    ) -> usize {
+        0
+    }
+}
+
+impl HolidayCalendar for HashSet<NaiveDate> {
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        self.contains(&date)
+    }
+
+    fn count_holidays(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+        counts: &dyn Fn(NaiveDate) -> bool,
+    ) -> usize {
+        self.iter()
+            .filter(|date| start <= **date && **date <= end && counts(**date))
+            .count()
+    }
+}
+
+impl<C: HolidayCalendar + ?Sized> HolidayCalendar for &C {
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        (**self).is_holiday(date)
+    }
+
+    fn count_holidays(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+        counts: &dyn Fn(NaiveDate) -> bool,
+    ) -> usize {
+        (**self).count_holidays(start, end, counts)
+    }
+}
+
+/// Returns the first business day after `date`, skipping weekends and holidays
+///
+/// Saturdays and Sundays are the weekend. `date` itself is never returned,
+/// even if it is a business day.
+///
+/// # Panics
+/// Panics if no business day follows `date` within the range of `NaiveDate`.
+///
+/// # Examples
+/// ```
+/// use chrono_kit::iter::next_business_day;
+/// use chrono::NaiveDate;
+/// use std::collections::HashSet;
+///
+/// let friday = NaiveDate::from_ymd_opt(2023, 1, 6).unwrap();
+/// let monday = NaiveDate::from_ymd_opt(2023, 1, 9).unwrap();
+/// let tuesday = NaiveDate::from_ymd_opt(2023, 1, 10).unwrap();
+/// assert_eq!(next_business_day(friday, &HashSet::from([monday])), tuesday);
+/// ```
+pub fn next_business_day(date: NaiveDate, calendar: &impl HolidayCalendar) -> NaiveDate {
+    date.iter_days()
+        .skip(1)
+        .find(|day| is_business_day(*day, calendar))
+        .expect("business day out of range")
+}
+
+/// Iterator over the business days between two dates
+///
+/// Saturdays and Sundays are skipped by default; use
+/// [`with_weekend_days`](Self::with_weekend_days) for other weekends and
+/// [`with_holidays`](Self::with_holidays) to skip holidays as well. Dates are
+/// yielded ascending from `start`, or descending from `end` via
+/// [`next_back`](DoubleEndedIterator::next_back) or `rev()`. The number of
+/// remaining days is computed arithmetically, so `count()` and `len()` do not
+/// walk the range unless the holiday calendar has to be checked day by day
+/// (see [`HolidayCalendar::count_holidays`]).
+///
+/// # Examples
+/// ```
+/// use chrono_kit::iter::BusinessDayIterator;
+/// use chrono::NaiveDate;
+///
+/// let friday = NaiveDate::from_ymd_opt(2023, 1, 6).unwrap();
+/// let monday = NaiveDate::from_ymd_opt(2023, 1, 9).unwrap();
+///
+/// let mut iter = BusinessDayIterator::new(friday, monday).unwrap();
+/// assert_eq!(iter.next(), Some(friday));
+/// assert_eq!(iter.next(), Some(monday));
+/// assert_eq!(iter.next(), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct BusinessDayIterator<H = ()> {
+    /// The dates not yet walked over, inclusive at both ends
+    remaining: Option<(NaiveDate, NaiveDate)>,
+    /// Weekend flags indexed by days from Monday
+    weekend: [bool; 7],
+    holidays: H,
+}
+
+impl BusinessDayIterator {
+    /// Creates a new BusinessDayIterator with a Saturday and Sunday weekend
+    ///
+    /// # Arguments
+    /// * `start` - The first date considered (inclusive)
+    /// * `end` - The last date considered (inclusive)
+    ///
+    /// # Errors
+    /// Returns `NaiveDateIterError::InvalidRange` if `start` is after `end`.
+    pub fn new(start: NaiveDate, end: NaiveDate) -> Result<Self, NaiveDateIterError> {
+        if start > end {
+            return Err(NaiveDateIterError::InvalidRange { start, end });
+        }
+        Ok(BusinessDayIterator {
+            remaining: Some((start, end)),
+            weekend: [false, false, false, false, false, true, true],
+            holidays: (),
+        })
+    }
+}
+
+impl<H: HolidayCalendar> BusinessDayIterator<H> {
+    /// Replaces the weekend with `weekend_days`
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono_kit::iter::BusinessDayIterator;
+    /// use chrono::{NaiveDate, Weekday};
+    ///
+    /// let friday = NaiveDate::from_ymd_opt(2023, 1, 6).unwrap();
+    /// let sunday = NaiveDate::from_ymd_opt(2023, 1, 8).unwrap();
+    ///
+    /// let iter = BusinessDayIterator::new(friday, sunday)
+    ///     .unwrap()
+    ///     .with_weekend_days(&[Weekday::Fri, Weekday::Sat]);
+    /// assert_eq!(iter.collect::<Vec<_>>(), vec![sunday]);
+    /// ```
+    pub fn with_weekend_days(mut self, weekend_days: &[Weekday]) -> Self {
+        self.weekend = [false; 7];
+        for day in weekend_days {
+            self.weekend[day.num_days_from_monday() as usize] = true;
+        }
+        self
+    }
+
+    /// Replaces the holiday calendar, skipping its holidays as well as weekends
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono_kit::iter::BusinessDayIterator;
+    /// use chrono::NaiveDate;
+    /// use std::collections::HashSet;
+    ///
+    /// let friday = NaiveDate::from_ymd_opt(2023, 1, 6).unwrap();
+    /// let monday = NaiveDate::from_ymd_opt(2023, 1, 9).unwrap();
+    ///
+    /// let iter = BusinessDayIterator::new(friday, monday)
+    ///     .unwrap()
+    ///     .with_holidays(HashSet::from([monday]));
+    /// assert_eq!(iter.collect::<Vec<_>>(), vec![friday]);
+    /// ```
+    pub fn with_holidays<C: HolidayCalendar>(self, calendar: C) -> BusinessDayIterator<C> {
+        BusinessDayIterator {
+            remaining: self.remaining,
+            weekend: self.weekend,
+            holidays: calendar,
+        }
+    }
+
+    /// Converts the business days into `(open, close)` ranges
+    ///
+    /// Each business day opens at midnight and closes at the following
+    /// midnight, so the weekends and holidays in between are skipped. With
+    /// `merge_closures`, each range instead stays open until the next business
+    /// day opens, so a long weekend collapses into the range of the business
+    /// day before it. The last range always closes at the midnight after the
+    /// last business day, since the next one lies beyond the iterator.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono_kit::iter::BusinessDayIterator;
+    /// use chrono::NaiveDate;
+    ///
+    /// let friday = NaiveDate::from_ymd_opt(2023, 1, 6).unwrap();
+    /// let monday = NaiveDate::from_ymd_opt(2023, 1, 9).unwrap();
+    /// let open = |date: NaiveDate| date.and_hms_opt(0, 0, 0).unwrap();
+    ///
+    /// let ranges: Vec<_> = BusinessDayIterator::new(friday, monday)
+    ///     .unwrap()
+    ///     .as_open_ranges(true)
+    ///     .collect();
+    /// assert_eq!(ranges[0], (open(friday), open(monday)));
+    /// ```
+    pub fn as_open_ranges(
+        self,
+        merge_closures: bool,
+    ) -> impl Iterator<Item = (NaiveDateTime, NaiveDateTime)> {
+        let mut days = self.peekable();
+        std::iter::from_fn(move || {
+            let day = days.next()?;
+            let next_open = match days.peek() {
+                Some(next) if merge_closures => *next,
+                _ => day.succ_opt().expect("business day out of range"),
+            };
+            Some((midnight(day), midnight(next_open)))
+        })
+    }
+
+    fn is_weekday(&self, date: NaiveDate) -> bool {
+        !self.weekend[date.weekday().num_days_from_monday() as usize]
+    }
+
+    fn is_business_day(&self, date: NaiveDate) -> bool {
+        self.is_weekday(date) && !self.holidays.is_holiday(date)
+    }
+
+    /// Returns the number of business days left, computed without iterating
+    fn remaining_len(&self) -> usize {
+        let Some((front, back)) = self.remaining else {
+            return 0;
+        };
+
+        let days = (back - front).num_days() as usize + 1;
+        let per_week = self.weekend.iter().filter(|weekend| !**weekend).count();
+        let first = front.weekday().num_days_from_monday() as usize;
+        let partial = (0..days % 7)
+            .filter(|offset| !self.weekend[(first + offset) % 7])
+            .count();
+        let holidays = self
+            .holidays
+            .count_holidays(front, back, &|date| self.is_weekday(date));
+        days / 7 * per_week + partial - holidays
+    }
+}
+
+impl<H: HolidayCalendar> Iterator for BusinessDayIterator<H> {
+    type Item = NaiveDate;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.weekend.iter().all(|weekend| *weekend) {
+            self.remaining = None;
+        }
+        while let Some((front, back)) = self.remaining {
+            self.remaining = front
+                .succ_opt()
+                .filter(|next| *next <= back)
+                .map(|next| (next, back));
+            if self.is_business_day(front) {
+                return Some(front);
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.remaining_len();
+        (len, Some(len))
+    }
+
+    fn count(self) -> usize {
+        self.remaining_len()
+    }
+}
+
+impl<H: HolidayCalendar> DoubleEndedIterator for BusinessDayIterator<H> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.weekend.iter().all(|weekend| *weekend) {
+            self.remaining = None;
+        }
+        while let Some((front, back)) = self.remaining {
+            self.remaining = back
+                .pred_opt()
+                .filter(|prev| *prev >= front)
+                .map(|prev| (front, prev));
+            if self.is_business_day(back) {
+                return Some(back);
+            }
+        }
+        None
+    }
+}
+
+impl<H: HolidayCalendar> ExactSizeIterator for BusinessDayIterator<H> {}
+
+impl NaiveDatetimeIterator {
+    /// Yields only the datetimes falling on business days
+    ///
+    /// Datetimes on Saturdays, Sundays, or dates in `holidays` are skipped.
+    /// The grid spacing is kept, so sub-daily steps yield every point of each
+    /// business day.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono_kit::iter::NaiveDatetimeIterator;
+    /// use chrono::{NaiveDateTime, Duration};
+    /// use std::collections::HashSet;
+    ///
+    /// let friday = NaiveDateTime::parse_from_str("2023-01-06 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let monday = NaiveDateTime::parse_from_str("2023-01-09 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    ///
+    /// let iter = NaiveDatetimeIterator::new(friday, monday, Duration::days(1)).unwrap();
+    /// let days: Vec<_> = iter.business_days_only(&HashSet::new()).collect();
+    /// assert_eq!(days, vec![friday, monday]);
+    /// ```
+    pub fn business_days_only(
+        self,
+        holidays: &HashSet<NaiveDate>,
+    ) -> impl Iterator<Item = NaiveDateTime> {
+        self.filter(move |dt| is_business_day(dt.date(), holidays))
+    }
+
+    /// Collects the datetimes whose date is in `holidays`
+    ///
+    /// This is the complement of the holiday check in
+    /// [`business_days_only`](Self::business_days_only): weekends are not
+    /// considered, only membership in `holidays`.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono_kit::iter::NaiveDatetimeIterator;
+    /// use chrono::{NaiveDate, NaiveDateTime, Duration};
+    /// use std::collections::HashSet;
+    ///
+    /// let start = NaiveDateTime::parse_from_str("2023-12-24 12:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let end = NaiveDateTime::parse_from_str("2023-12-26 12:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let holidays = HashSet::from([NaiveDate::from_ymd_opt(2023, 12, 25).unwrap()]);
+    ///
+    /// let iter = NaiveDatetimeIterator::new(start, end, Duration::days(1)).unwrap();
+    /// assert_eq!(iter.points_on_holidays(&holidays), vec![start + Duration::days(1)]);
+    /// ```
+    pub fn points_on_holidays(self, holidays: &HashSet<NaiveDate>) -> Vec<NaiveDateTime> {
+        self.filter(|dt| holidays.contains(&dt.date())).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, Timelike};
+
+    fn dt(s: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_add_business_days_across_weekend() {
+        let holidays = HashSet::new();
+        assert_eq!(
+            add_business_days(date(2023, 1, 4), 5, &holidays),
+            date(2023, 1, 11)
+        );
+        assert_eq!(
+            add_business_days(date(2023, 1, 7), 1, &holidays),
+            date(2023, 1, 9)
+        );
+        assert_eq!(
+            add_business_days(date(2023, 1, 7), 0, &holidays),
+            date(2023, 1, 7)
+        );
+    }
+
+    #[test]
+    fn test_add_business_days_across_holiday() {
+        let holidays = HashSet::from([date(2023, 1, 9), date(2023, 1, 10)]);
+        assert_eq!(
+            add_business_days(date(2023, 1, 6), 1, &holidays),
+            date(2023, 1, 11)
+        );
+        assert_eq!(
+            add_business_days(date(2023, 1, 6), 3, &holidays),
+            date(2023, 1, 13)
+        );
+    }
+
+    #[test]
+    fn test_every_nth_business_day_spacing() {
+        let holidays = HashSet::from([date(2023, 1, 16)]);
+        let days: Vec<_> =
+            every_nth_business_day(date(2023, 1, 1), date(2023, 1, 31), 5, &holidays).collect();
+        assert_eq!(
+            days,
+            vec![
+                date(2023, 1, 2),
+                date(2023, 1, 9),
+                date(2023, 1, 17),
+                date(2023, 1, 24),
+                date(2023, 1, 31),
+            ]
+        );
+
+        for pair in days.windows(2) {
+            assert_eq!(add_business_days(pair[0], 5, &holidays), pair[1]);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_every_nth_business_day_zero() {
+        every_nth_business_day(date(2023, 1, 2), date(2023, 1, 31), 0, &HashSet::new()).count();
+    }
+
+    #[test]
+    fn test_add_business_days_negative() {
+        let holidays = HashSet::from([date(2023, 1, 6)]);
+        assert_eq!(
+            add_business_days(date(2023, 1, 9), -1, &holidays),
+            date(2023, 1, 5)
+        );
+        assert_eq!(
+            add_business_days(date(2023, 1, 11), -5, &holidays),
+            date(2023, 1, 3)
+        );
+    }
+
+    #[test]
+    fn test_business_day_iterator_weekend_bounds() {
+        let saturday = date(2023, 1, 7);
+        let sunday = date(2023, 1, 15);
+
+        let days: Vec<_> = BusinessDayIterator::new(saturday, sunday)
+            .unwrap()
+            .collect();
+        assert_eq!(
+            days,
+            (9..=13).map(|day| date(2023, 1, day)).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            BusinessDayIterator::new(saturday, sunday).unwrap().count(),
+            5
+        );
+
+        let reversed: Vec<_> = BusinessDayIterator::new(saturday, sunday)
+            .unwrap()
+            .rev()
+            .collect();
+        assert_eq!(reversed, days.into_iter().rev().collect::<Vec<_>>());
+
+        let weekend = BusinessDayIterator::new(saturday, saturday.succ_opt().unwrap()).unwrap();
+        assert_eq!(weekend.len(), 0);
+        assert_eq!(weekend.collect::<Vec<_>>(), vec![]);
+    }
+
+    #[test]
+    fn test_business_day_iterator_custom_weekend() {
+        let iter = BusinessDayIterator::new(date(2023, 1, 1), date(2023, 1, 31))
+            .unwrap()
+            .with_weekend_days(&[Weekday::Fri, Weekday::Sat]);
+        assert_eq!(iter.len(), 23);
+
+        let days: Vec<_> = iter.collect();
+        assert_eq!(days.len(), 23);
+        assert!(
+            days.iter()
+                .all(|day| !matches!(day.weekday(), Weekday::Fri | Weekday::Sat))
+        );
+
+        let iter = BusinessDayIterator::new(date(2023, 1, 1), date(2023, 1, 31))
+            .unwrap()
+            .with_weekend_days(&[]);
+        assert_eq!(iter.count(), 31);
+    }
+
+    #[test]
+    fn test_business_day_iterator_weekday_vs_weekend_holiday() {
+        let start = date(2023, 1, 2);
+        let end = date(2023, 1, 15);
+        let weekday_holiday = date(2023, 1, 10);
+        let weekend_holiday = date(2023, 1, 14);
+
+        let plain = BusinessDayIterator::new(start, end).unwrap();
+        assert_eq!(plain.count(), 10);
+
+        let holidays = HashSet::from([weekend_holiday]);
+        let iter = BusinessDayIterator::new(start, end)
+            .unwrap()
+            .with_holidays(&holidays);
+        assert_eq!(iter.len(), 10);
+        assert_eq!(iter.count(), 10);
+
+        let holidays = HashSet::from([weekday_holiday, weekend_holiday]);
+        let iter = BusinessDayIterator::new(start, end)
+            .unwrap()
+            .with_holidays(&holidays);
+        assert_eq!(iter.len(), 9);
+        let days: Vec<_> = iter.collect();
+        assert_eq!(days.len(), 9);
+        assert!(!days.contains(&weekday_holiday));
+    }
+
+    #[test]
+    fn test_business_day_iterator_all_holidays() {
+        struct Closed;
+
+        impl HolidayCalendar for Closed {
+            fn is_holiday(&self, _date: NaiveDate) -> bool {
+                true
+            }
+        }
+
+        let mut iter = BusinessDayIterator::new(date(2023, 1, 2), date(2023, 1, 31))
+            .unwrap()
+            .with_holidays(Closed);
+        assert_eq!(iter.len(), 0);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn test_next_business_day() {
+        let holidays = HashSet::from([date(2023, 1, 9), date(2023, 1, 7)]);
+        assert_eq!(
+            next_business_day(date(2023, 1, 5), &holidays),
+            date(2023, 1, 6)
+        );
+        assert_eq!(
+            next_business_day(date(2023, 1, 6), &holidays),
+            date(2023, 1, 10)
+        );
+        assert_eq!(next_business_day(date(2023, 1, 7), &()), date(2023, 1, 9));
+    }
+
+    #[test]
+    fn test_business_day_count_matches_iteration() {
+        let start = date(2023, 1, 1);
+        for len in 0..20 {
+            let end = start + chrono::Days::new(len);
+            for weekend in [&[Weekday::Sat, Weekday::Sun][..], &[Weekday::Fri], &[]] {
+                let iter = || {
+                    BusinessDayIterator::new(start, end)
+                        .unwrap()
+                        .with_weekend_days(weekend)
+                };
+                let mut partial = iter();
+                partial.next();
+                partial.next_back();
+                assert_eq!(iter().count(), iter().fold(0, |n, _| n + 1));
+                assert_eq!(partial.len(), partial.fold(0, |n, _| n + 1));
+            }
+        }
+    }
+
+    #[test]
+    fn test_business_days_only_hourly_over_weekend() {
+        let iter = NaiveDatetimeIterator::new(
+            dt("2023-01-06 00:00:00"),
+            dt("2023-01-09 23:00:00"),
+            Duration::hours(1),
+        )
+        .unwrap();
+
+        let points: Vec<_> = iter.business_days_only(&HashSet::new()).collect();
+        assert_eq!(points.len(), 48);
+        assert!(
+            points
+                .iter()
+                .all(|p| p.weekday() == Weekday::Fri || p.weekday() == Weekday::Mon)
+        );
+        assert_eq!(points[23].hour(), 23);
+        assert_eq!(points[24], dt("2023-01-09 00:00:00"));
+    }
+
+    #[test]
+    fn test_business_days_only_with_holiday() {
+        let holidays = HashSet::from([NaiveDate::from_ymd_opt(2023, 1, 9).unwrap()]);
+        let iter = NaiveDatetimeIterator::new(
+            dt("2023-01-06 00:00:00"),
+            dt("2023-01-10 12:00:00"),
+            Duration::hours(-6),
+        )
+        .unwrap();
+
+        let points: Vec<_> = iter.business_days_only(&holidays).collect();
+        assert_eq!(
+            points,
+            vec![
+                dt("2023-01-10 12:00:00"),
+                dt("2023-01-10 06:00:00"),
+                dt("2023-01-10 00:00:00"),
+                dt("2023-01-06 18:00:00"),
+                dt("2023-01-06 12:00:00"),
+                dt("2023-01-06 06:00:00"),
+                dt("2023-01-06 00:00:00"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_points_on_holidays_daily() {
+        let holidays = HashSet::from([date(2023, 1, 7)]);
+        let iter = NaiveDatetimeIterator::new(
+            dt("2023-01-01 09:00:00"),
+            dt("2023-01-10 09:00:00"),
+            Duration::days(1),
+        )
+        .unwrap();
+
+        assert_eq!(
+            iter.points_on_holidays(&holidays),
+            vec![dt("2023-01-07 09:00:00")]
+        );
+    }
+
+    #[test]
+    fn test_points_on_holidays_sub_daily() {
+        let holidays = HashSet::from([date(2023, 1, 2), date(2023, 2, 1)]);
+        let iter = NaiveDatetimeIterator::new(
+            dt("2023-01-01 00:00:00"),
+            dt("2023-01-03 00:00:00"),
+            Duration::hours(-8),
+        )
+        .unwrap();
+
+        assert_eq!(
+            iter.points_on_holidays(&holidays),
+            vec![
+                dt("2023-01-02 16:00:00"),
+                dt("2023-01-02 08:00:00"),
+                dt("2023-01-02 00:00:00"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_as_open_ranges_skip_midweek_holiday() {
+        let ranges: Vec<_> = BusinessDayIterator::new(date(2023, 1, 2), date(2023, 1, 8))
+            .unwrap()
+            .with_holidays(HashSet::from([date(2023, 1, 4)]))
+            .as_open_ranges(false)
+            .collect();
+        assert_eq!(
+            ranges,
+            vec![
+                (dt("2023-01-02 00:00:00"), dt("2023-01-03 00:00:00")),
+                (dt("2023-01-03 00:00:00"), dt("2023-01-04 00:00:00")),
+                (dt("2023-01-05 00:00:00"), dt("2023-01-06 00:00:00")),
+                (dt("2023-01-06 00:00:00"), dt("2023-01-07 00:00:00")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_as_open_ranges_merge_closures() {
+        let ranges: Vec<_> = BusinessDayIterator::new(date(2023, 1, 2), date(2023, 1, 10))
+            .unwrap()
+            .with_holidays(HashSet::from([date(2023, 1, 4), date(2023, 1, 9)]))
+            .as_open_ranges(true)
+            .collect();
+        assert_eq!(
+            ranges,
+            vec![
+                (dt("2023-01-02 00:00:00"), dt("2023-01-03 00:00:00")),
+                (dt("2023-01-03 00:00:00"), dt("2023-01-05 00:00:00")),
+                (dt("2023-01-05 00:00:00"), dt("2023-01-06 00:00:00")),
+                (dt("2023-01-06 00:00:00"), dt("2023-01-10 00:00:00")),
+                (dt("2023-01-10 00:00:00"), dt("2023-01-11 00:00:00")),
+            ]
+        );
+    }
+}
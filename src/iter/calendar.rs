@@ -0,0 +1,923 @@
+use super::naive_datetime_iter::{NaiveDatetimeIterError, NaiveDatetimeIterator};
+use super::naive_datetime_range_iter::NaiveDatetimeRangeIterator;
+use chrono::{Datelike, Days, Duration, Months, NaiveDate, NaiveDateTime, Weekday};
+use std::collections::{BTreeMap, VecDeque};
+
+/// Resolution used when computing the last instant of a day
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DayEndPrecision {
+    /// The last whole second, `23:59:59`
+    Second,
+    /// The last representable nanosecond, `23:59:59.999999999`
+    Nanosecond,
+}
+
+/// Calendar period used to label ranges, see [`NaiveDatetimeRangeIterator::labeled`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PeriodLabel {
+    /// ISO 8601 week, such as `2023-W01`, using the ISO week-numbering year
+    IsoWeek,
+    /// Calendar month, such as `2023-01`
+    Month,
+    /// Calendar quarter, such as `2023-Q1`
+    Quarter,
+    /// Calendar year, such as `2023`
+    Year,
+}
+
+impl PeriodLabel {
+    /// Formats the label of the period containing `dt`
+    fn format(self, dt: NaiveDateTime) -> String {
+        match self {
+            PeriodLabel::IsoWeek => {
+                let week = dt.iso_week();
+                format!("{}-W{:02}", week.year(), week.week())
+            }
+            PeriodLabel::Month => format!("{}-{:02}", dt.year(), dt.month()),
+            PeriodLabel::Quarter => format!("{}-Q{}", dt.year(), dt.month0() / 3 + 1),
+            PeriodLabel::Year => dt.year().to_string(),
+        }
+    }
+}
+
+/// Yields the last nanosecond of each day from `start` to `end` inclusive
+///
+/// Yields nothing if `start` is after `end`. See [`end_of_day_with_precision`]
+/// to end days on whole seconds instead.
+///
+/// # Examples
+/// ```
+/// use chrono_kit::iter::end_of_day;
+/// use chrono::NaiveDate;
+///
+/// let start = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+/// let end = NaiveDate::from_ymd_opt(2023, 1, 2).unwrap();
+///
+/// let mut iter = end_of_day(start, end);
+/// assert_eq!(iter.next(), start.and_hms_nano_opt(23, 59, 59, 999_999_999));
+/// assert_eq!(iter.next(), end.and_hms_nano_opt(23, 59, 59, 999_999_999));
+/// assert_eq!(iter.next(), None);
+/// ```
+pub fn end_of_day(start: NaiveDate, end: NaiveDate) -> impl Iterator<Item = NaiveDateTime> {
+    end_of_day_with_precision(start, end, DayEndPrecision::Nanosecond)
+}
+
+/// Yields the last instant of each day from `start` to `end` inclusive at the given precision
+///
+/// # Examples
+/// ```
+/// use chrono_kit::iter::{end_of_day_with_precision, DayEndPrecision};
+/// use chrono::NaiveDate;
+///
+/// let day = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+///
+/// let mut iter = end_of_day_with_precision(day, day, DayEndPrecision::Second);
+/// assert_eq!(iter.next(), day.and_hms_opt(23, 59, 59));
+/// ```
+pub fn end_of_day_with_precision(
+    start: NaiveDate,
+    end: NaiveDate,
+    precision: DayEndPrecision,
+) -> impl Iterator<Item = NaiveDateTime> {
+    let nanos = match precision {
+        DayEndPrecision::Second => 0,
+        DayEndPrecision::Nanosecond => 999_999_999,
+    };
+
+    start
+        .iter_days()
+        .take_while(move |date| *date <= end)
+        .map(move |date| date.and_hms_nano_opt(23, 59, 59, nanos).unwrap())
+}
+
+/// Returns the first month start strictly after `dt`
+fn next_month_start(dt: NaiveDateTime) -> Option<NaiveDateTime> {
+    NaiveDate::from_ymd_opt(dt.year(), dt.month(), 1)?
+        .checked_add_months(Months::new(1))
+        .map(|date| date.and_hms_opt(0, 0, 0).unwrap())
+}
+
+/// Returns the first midnight starting a `week_start` day strictly after `dt`
+fn next_week_start(dt: NaiveDateTime, week_start: Weekday) -> Option<NaiveDateTime> {
+    let days_ahead =
+        (week_start.num_days_from_monday() + 7 - dt.weekday().num_days_from_monday()) % 7;
+    let days_ahead = if days_ahead == 0 { 7 } else { days_ahead };
+    dt.date()
+        .checked_add_days(Days::new(days_ahead.into()))
+        .map(|date| date.and_hms_opt(0, 0, 0).unwrap())
+}
+
+/// Rounds `dt` down to midnight of its day
+fn floor_to_day(dt: NaiveDateTime) -> NaiveDateTime {
+    dt.date().and_hms_opt(0, 0, 0).unwrap()
+}
+
+/// Rounds `dt` up to the next midnight, leaving midnight itself unchanged
+fn ceil_to_day(dt: NaiveDateTime) -> NaiveDateTime {
+    let floor = floor_to_day(dt);
+    if floor == dt {
+        return dt;
+    }
+    floor.date().succ_opt().map_or(NaiveDateTime::MAX, |date| {
+        date.and_hms_opt(0, 0, 0).unwrap()
+    })
+}
+
+/// Splits `(start, end)` at every boundary produced by `next_boundary`
+///
+/// Pieces are returned in ascending order, or descending if `asc` is `false`.
+fn split_range(
+    (start, end): (NaiveDateTime, NaiveDateTime),
+    asc: bool,
+    next_boundary: impl Fn(NaiveDateTime) -> Option<NaiveDateTime>,
+) -> Vec<(NaiveDateTime, NaiveDateTime)> {
+    let mut pieces = Vec::new();
+    let mut piece_start = start;
+    while let Some(boundary) = next_boundary(piece_start).filter(|b| *b < end) {
+        pieces.push((piece_start, boundary));
+        piece_start = boundary;
+    }
+    pieces.push((piece_start, end));
+
+    if !asc {
+        pieces.reverse();
+    }
+    pieces
+}
+
+impl NaiveDatetimeIterator {
+    /// Yields at most `n` points for each calendar date
+    ///
+    /// The chronologically earliest `n` points of every date are kept and the
+    /// rest are skipped, whichever way the iterator walks. For descending steps
+    /// these are the last `n` of each date in iteration order, so up to `n`
+    /// points are held back until the date changes.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono_kit::iter::NaiveDatetimeIterator;
+    /// use chrono::{NaiveDateTime, Duration};
+    ///
+    /// let start = NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let end = NaiveDateTime::parse_from_str("2023-01-02 12:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    ///
+    /// let iter = NaiveDatetimeIterator::new(start, end, Duration::hours(6)).unwrap();
+    /// assert_eq!(iter.cap_per_day(1).count(), 2);
+    /// ```
+    pub fn cap_per_day(self, n: usize) -> impl Iterator<Item = NaiveDateTime> {
+        let (_, _, _, flipped) = self.window();
+        let ascending = self.full_step().is_positive() != flipped;
+        let mut points = self.peekable();
+        let mut kept = VecDeque::new();
+
+        std::iter::from_fn(move || {
+            loop {
+                if let Some(dt) = kept.pop_front() {
+                    return Some(dt);
+                }
+                let first = points.next()?;
+                let date = first.date();
+                let mut day = std::iter::once(first).chain(std::iter::from_fn(|| {
+                    points.next_if(|dt| dt.date() == date)
+                }));
+                if ascending {
+                    kept.extend(day.by_ref().take(n));
+                    day.for_each(drop);
+                } else {
+                    for dt in day {
+                        kept.push_back(dt);
+                        if kept.len() > n {
+                            kept.pop_front();
+                        }
+                    }
+                }
+            }
+        })
+    }
+
+    /// Pairs each datetime with its ISO weekday number, Monday = 1 through Sunday = 7
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono_kit::iter::NaiveDatetimeIterator;
+    /// use chrono::{NaiveDateTime, Duration};
+    ///
+    /// let monday = NaiveDateTime::parse_from_str("2023-01-02 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    ///
+    /// let mut iter = NaiveDatetimeIterator::new(monday, monday, Duration::days(1)).unwrap().with_iso_weekday();
+    /// assert_eq!(iter.next(), Some((monday, 1)));
+    /// ```
+    pub fn with_iso_weekday(self) -> impl Iterator<Item = (NaiveDateTime, u8)> {
+        self.map(|dt| (dt, dt.weekday().number_from_monday() as u8))
+    }
+
+    /// Returns the distinct `(year, month)` pairs touched by the iterator's range
+    ///
+    /// The result is computed from the bounds given at construction rather than
+    /// by iterating, and is sorted chronologically regardless of step direction.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono_kit::iter::NaiveDatetimeIterator;
+    /// use chrono::{NaiveDateTime, Duration};
+    ///
+    /// let start = NaiveDateTime::parse_from_str("2023-01-15 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let end = NaiveDateTime::parse_from_str("2023-03-03 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    ///
+    /// let iter = NaiveDatetimeIterator::new(start, end, Duration::days(1)).unwrap();
+    /// assert_eq!(iter.spanned_months(), vec![(2023, 1), (2023, 2), (2023, 3)]);
+    /// ```
+    pub fn spanned_months(&self) -> Vec<(i32, u32)> {
+        let (start, end) = self.original_bounds();
+        let (mut year, mut month) = (start.year(), start.month());
+        let mut months = Vec::new();
+
+        while (year, month) <= (end.year(), end.month()) {
+            months.push((year, month));
+            if month == 12 {
+                year += 1;
+                month = 1;
+            } else {
+                month += 1;
+            }
+        }
+        months
+    }
+
+    /// Returns the distinct calendar years touched by the iterator's range
+    ///
+    /// The result is computed from the bounds given at construction rather than
+    /// by iterating, and is sorted ascending regardless of step direction.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono_kit::iter::NaiveDatetimeIterator;
+    /// use chrono::{NaiveDateTime, Duration};
+    ///
+    /// let start = NaiveDateTime::parse_from_str("2022-12-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let end = NaiveDateTime::parse_from_str("2024-01-31 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    ///
+    /// let iter = NaiveDatetimeIterator::new(start, end, Duration::days(1)).unwrap();
+    /// assert_eq!(iter.spanned_years(), vec![2022, 2023, 2024]);
+    /// ```
+    pub fn spanned_years(&self) -> Vec<i32> {
+        let (start, end) = self.original_bounds();
+        (start.year()..=end.year()).collect()
+    }
+
+    /// Counts the datetimes falling on each day, grouped by calendar month
+    ///
+    /// The map is keyed by `(year, month)` and holds one count per day of that
+    /// month, indexed from the 1st, so the vector's length is the month's
+    /// length. Only months containing at least one datetime are present.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono_kit::iter::NaiveDatetimeIterator;
+    /// use chrono::{NaiveDateTime, Duration};
+    ///
+    /// let start = NaiveDateTime::parse_from_str("2023-02-27 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let end = NaiveDateTime::parse_from_str("2023-03-01 12:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    ///
+    /// let counts = NaiveDatetimeIterator::new(start, end, Duration::hours(12)).unwrap().monthly_daily_counts();
+    /// assert_eq!(counts[&(2023, 2)][26..], [2, 2]);
+    /// assert_eq!(counts[&(2023, 3)][0], 2);
+    /// ```
+    pub fn monthly_daily_counts(self) -> BTreeMap<(i32, u32), Vec<usize>> {
+        let mut counts = BTreeMap::new();
+        for dt in self {
+            let days = counts
+                .entry((dt.year(), dt.month()))
+                .or_insert_with(|| vec![0; dt.num_days_in_month() as usize]);
+            days[dt.day0() as usize] += 1;
+        }
+        counts
+    }
+}
+
+impl NaiveDatetimeRangeIterator {
+    /// Splits ranges so that each one lies within a single calendar month
+    ///
+    /// A break is inserted at the start of every month crossed by a range.
+    /// Ranges already contained in one month pass through unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono_kit::iter::NaiveDatetimeRangeIterator;
+    /// use chrono::{NaiveDateTime, Duration};
+    ///
+    /// let start = NaiveDateTime::parse_from_str("2023-01-20 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let end = NaiveDateTime::parse_from_str("2023-02-09 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let month_start = NaiveDateTime::parse_from_str("2023-02-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    ///
+    /// let iter = NaiveDatetimeRangeIterator::new(start, end, Duration::days(20)).unwrap();
+    /// let ranges: Vec<_> = iter.clip_to_months().collect();
+    /// assert_eq!(ranges, vec![(start, month_start), (month_start, end)]);
+    /// ```
+    pub fn clip_to_months(self) -> impl Iterator<Item = (NaiveDateTime, NaiveDateTime)> {
+        let asc = self.is_ascending();
+        self.flat_map(move |range| split_range(range, asc, next_month_start))
+    }
+
+    /// Splits ranges so that none crosses the start of a week
+    ///
+    /// A break is inserted at midnight of every `week_start` day crossed by a
+    /// range. Ranges already contained in one week pass through unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono_kit::iter::NaiveDatetimeRangeIterator;
+    /// use chrono::{NaiveDateTime, Duration, Weekday};
+    ///
+    /// let start = NaiveDateTime::parse_from_str("2023-01-06 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let end = NaiveDateTime::parse_from_str("2023-01-10 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let monday = NaiveDateTime::parse_from_str("2023-01-09 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    ///
+    /// let iter = NaiveDatetimeRangeIterator::new(start, end, Duration::days(4)).unwrap();
+    /// let ranges: Vec<_> = iter.split_on_week_boundaries(Weekday::Mon).collect();
+    /// assert_eq!(ranges, vec![(start, monday), (monday, end)]);
+    /// ```
+    pub fn split_on_week_boundaries(
+        self,
+        week_start: Weekday,
+    ) -> impl Iterator<Item = (NaiveDateTime, NaiveDateTime)> {
+        let asc = self.is_ascending();
+        self.flat_map(move |range| split_range(range, asc, |dt| next_week_start(dt, week_start)))
+    }
+
+    /// Splits ranges longer than `max` into consecutive pieces of at most `max`
+    ///
+    /// Pieces are cut every `max` from the start of the range, so only the
+    /// last piece of a split range may be shorter. Ranges no longer than `max`
+    /// pass through unchanged. The sign of `max` is ignored.
+    ///
+    /// # Errors
+    /// Returns `NaiveDatetimeIterError::ZeroStep` if `max` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono_kit::iter::NaiveDatetimeRangeIterator;
+    /// use chrono::{NaiveDateTime, Duration};
+    ///
+    /// let start = NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let end = NaiveDateTime::parse_from_str("2023-01-11 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let week = Duration::days(7);
+    ///
+    /// let iter = NaiveDatetimeRangeIterator::new(start, end, Duration::days(10)).unwrap();
+    /// let ranges: Vec<_> = iter.cap_duration(week).unwrap().collect();
+    /// assert_eq!(ranges, vec![(start, start + week), (start + week, end)]);
+    /// ```
+    pub fn cap_duration(
+        self,
+        max: Duration,
+    ) -> Result<impl Iterator<Item = (NaiveDateTime, NaiveDateTime)>, NaiveDatetimeIterError> {
+        if max.is_zero() {
+            return Err(NaiveDatetimeIterError::ZeroStep);
+        }
+
+        let max = max.abs();
+        let asc = self.is_ascending();
+        Ok(self.flat_map(move |range| split_range(range, asc, |dt| dt.checked_add_signed(max))))
+    }
+
+    /// Extends the outermost ranges to whole-day boundaries
+    ///
+    /// The earliest range is extended back to midnight of its day and the
+    /// latest range forward to the following midnight (unless it already ends
+    /// at midnight). Interior ranges are unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono_kit::iter::NaiveDatetimeRangeIterator;
+    /// use chrono::{NaiveDateTime, Duration};
+    ///
+    /// let start = NaiveDateTime::parse_from_str("2023-01-01 09:30:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let end = NaiveDateTime::parse_from_str("2023-01-01 17:30:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let midnight = NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    ///
+    /// let iter = NaiveDatetimeRangeIterator::new(start, end, Duration::hours(8)).unwrap();
+    /// let ranges: Vec<_> = iter.pad_to_day_bounds().collect();
+    /// assert_eq!(ranges, vec![(midnight, midnight + Duration::days(1))]);
+    /// ```
+    pub fn pad_to_day_bounds(self) -> impl Iterator<Item = (NaiveDateTime, NaiveDateTime)> {
+        let asc = self.is_ascending();
+        let mut is_first = true;
+        let mut ranges = self.peekable();
+
+        std::iter::from_fn(move || {
+            let (mut start, mut end) = ranges.next()?;
+            let is_last = ranges.peek().is_none();
+            let (pads_start, pads_end) = if asc {
+                (is_first, is_last)
+            } else {
+                (is_last, is_first)
+            };
+            is_first = false;
+
+            if pads_start {
+                start = floor_to_day(start);
+            }
+            if pads_end {
+                end = ceil_to_day(end);
+            }
+            Some((start, end))
+        })
+    }
+
+    /// Tags each range with the label of the period containing its start
+    ///
+    /// The start is the earlier bound of each range, for either step
+    /// direction. Ranges are not split at period boundaries; combine with
+    /// [`clip_to_months`](Self::clip_to_months) or
+    /// [`split_on_week_boundaries`](Self::split_on_week_boundaries) first if
+    /// each range should lie within its labeled period.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono_kit::iter::{NaiveDatetimeRangeIterator, PeriodLabel};
+    /// use chrono::{NaiveDateTime, Duration};
+    ///
+    /// let start = NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let end = NaiveDateTime::parse_from_str("2023-01-15 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    ///
+    /// let iter = NaiveDatetimeRangeIterator::new(start, end, Duration::weeks(1)).unwrap();
+    /// let labels: Vec<_> = iter.labeled(PeriodLabel::IsoWeek).map(|(label, _, _)| label).collect();
+    /// assert_eq!(labels, vec!["2022-W52", "2023-W01"]);
+    /// ```
+    pub fn labeled(
+        self,
+        fmt: PeriodLabel,
+    ) -> impl Iterator<Item = (String, NaiveDateTime, NaiveDateTime)> {
+        self.map(move |(start, end)| (fmt.format(start), start, end))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn dt(s: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    #[test]
+    fn test_cap_per_day() {
+        let iter = NaiveDatetimeIterator::new(
+            dt("2023-01-01 00:00:00"),
+            dt("2023-01-03 12:00:00"),
+            Duration::minutes(30),
+        )
+        .unwrap();
+
+        let points: Vec<_> = iter.cap_per_day(2).collect();
+        assert_eq!(
+            points,
+            vec![
+                dt("2023-01-01 00:00:00"),
+                dt("2023-01-01 00:30:00"),
+                dt("2023-01-02 00:00:00"),
+                dt("2023-01-02 00:30:00"),
+                dt("2023-01-03 00:00:00"),
+                dt("2023-01-03 00:30:00"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_cap_per_day_zero() {
+        let iter = NaiveDatetimeIterator::new(
+            dt("2023-01-01 00:00:00"),
+            dt("2023-01-02 00:00:00"),
+            Duration::hours(1),
+        )
+        .unwrap();
+
+        assert_eq!(iter.cap_per_day(0).count(), 0);
+    }
+
+    #[test]
+    fn test_cap_per_day_descending_keeps_earliest() {
+        let iter = NaiveDatetimeIterator::new(
+            dt("2023-01-01 00:00:00"),
+            dt("2023-01-02 12:00:00"),
+            Duration::hours(-6),
+        )
+        .unwrap();
+
+        let points: Vec<_> = iter.cap_per_day(2).collect();
+        assert_eq!(
+            points,
+            vec![
+                dt("2023-01-02 06:00:00"),
+                dt("2023-01-02 00:00:00"),
+                dt("2023-01-01 06:00:00"),
+                dt("2023-01-01 00:00:00"),
+            ]
+        );
+
+        let flipped = NaiveDatetimeIterator::new(
+            dt("2023-01-01 00:00:00"),
+            dt("2023-01-02 12:00:00"),
+            Duration::hours(6),
+        )
+        .unwrap()
+        .reversed();
+        assert_eq!(flipped.cap_per_day(2).collect::<Vec<_>>(), points);
+    }
+
+    #[test]
+    fn test_end_of_day() {
+        let start = NaiveDate::from_ymd_opt(2023, 2, 27).unwrap();
+        let end = NaiveDate::from_ymd_opt(2023, 3, 1).unwrap();
+
+        let days: Vec<_> = end_of_day(start, end).collect();
+        assert_eq!(
+            days,
+            vec![
+                dt("2023-02-27 23:59:59") + Duration::nanoseconds(999_999_999),
+                dt("2023-02-28 23:59:59") + Duration::nanoseconds(999_999_999),
+                dt("2023-03-01 23:59:59") + Duration::nanoseconds(999_999_999),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_end_of_day_second_precision() {
+        let start = NaiveDate::from_ymd_opt(2023, 12, 31).unwrap();
+        let end = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+
+        let days: Vec<_> = end_of_day_with_precision(start, end, DayEndPrecision::Second).collect();
+        assert_eq!(
+            days,
+            vec![dt("2023-12-31 23:59:59"), dt("2024-01-01 23:59:59")]
+        );
+        assert_eq!(end_of_day(end, start).count(), 0);
+    }
+
+    #[test]
+    fn test_clip_to_months_within_month() {
+        let iter = NaiveDatetimeRangeIterator::new(
+            dt("2023-01-01 00:00:00"),
+            dt("2023-01-03 00:00:00"),
+            Duration::days(1),
+        )
+        .unwrap();
+
+        let ranges: Vec<_> = iter.clip_to_months().collect();
+        assert_eq!(
+            ranges,
+            vec![
+                (dt("2023-01-01 00:00:00"), dt("2023-01-02 00:00:00")),
+                (dt("2023-01-02 00:00:00"), dt("2023-01-03 00:00:00")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_clip_to_months_one_boundary() {
+        let iter = NaiveDatetimeRangeIterator::new(
+            dt("2023-01-30 12:00:00"),
+            dt("2023-02-02 12:00:00"),
+            Duration::days(2),
+        )
+        .unwrap();
+
+        let ranges: Vec<_> = iter.clip_to_months().collect();
+        assert_eq!(
+            ranges,
+            vec![
+                (dt("2023-01-30 12:00:00"), dt("2023-02-01 00:00:00")),
+                (dt("2023-02-01 00:00:00"), dt("2023-02-01 12:00:00")),
+                (dt("2023-02-01 12:00:00"), dt("2023-02-02 12:00:00")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_clip_to_months_two_boundaries() {
+        let iter = NaiveDatetimeRangeIterator::new(
+            dt("2023-01-15 00:00:00"),
+            dt("2023-03-10 00:00:00"),
+            Duration::days(-60),
+        )
+        .unwrap();
+
+        let ranges: Vec<_> = iter.clip_to_months().collect();
+        assert_eq!(
+            ranges,
+            vec![
+                (dt("2023-03-01 00:00:00"), dt("2023-03-10 00:00:00")),
+                (dt("2023-02-01 00:00:00"), dt("2023-03-01 00:00:00")),
+                (dt("2023-01-15 00:00:00"), dt("2023-02-01 00:00:00")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_split_on_week_boundaries_within_week() {
+        let iter = NaiveDatetimeRangeIterator::new(
+            dt("2023-01-09 00:00:00"),
+            dt("2023-01-15 12:00:00"),
+            Duration::days(3),
+        )
+        .unwrap();
+
+        let ranges: Vec<_> = iter.split_on_week_boundaries(Weekday::Mon).collect();
+        assert_eq!(
+            ranges,
+            vec![
+                (dt("2023-01-09 00:00:00"), dt("2023-01-12 00:00:00")),
+                (dt("2023-01-12 00:00:00"), dt("2023-01-15 00:00:00")),
+                (dt("2023-01-15 00:00:00"), dt("2023-01-15 12:00:00")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_split_on_week_boundaries_crossing() {
+        let iter = NaiveDatetimeRangeIterator::new(
+            dt("2023-01-05 12:00:00"),
+            dt("2023-01-12 12:00:00"),
+            Duration::days(-7),
+        )
+        .unwrap();
+
+        let ranges: Vec<_> = iter.split_on_week_boundaries(Weekday::Mon).collect();
+        assert_eq!(
+            ranges,
+            vec![
+                (dt("2023-01-09 00:00:00"), dt("2023-01-12 12:00:00")),
+                (dt("2023-01-05 12:00:00"), dt("2023-01-09 00:00:00")),
+            ]
+        );
+
+        let iter = NaiveDatetimeRangeIterator::new(
+            dt("2023-01-05 12:00:00"),
+            dt("2023-01-12 12:00:00"),
+            Duration::days(7),
+        )
+        .unwrap();
+        assert_eq!(iter.split_on_week_boundaries(Weekday::Sun).count(), 2);
+    }
+
+    #[test]
+    fn test_cap_duration_month_to_weeks() {
+        let iter = NaiveDatetimeRangeIterator::new(
+            dt("2023-01-01 00:00:00"),
+            dt("2023-02-01 00:00:00"),
+            Duration::days(31),
+        )
+        .unwrap();
+
+        let ranges: Vec<_> = iter.cap_duration(Duration::days(7)).unwrap().collect();
+        assert_eq!(
+            ranges,
+            vec![
+                (dt("2023-01-01 00:00:00"), dt("2023-01-08 00:00:00")),
+                (dt("2023-01-08 00:00:00"), dt("2023-01-15 00:00:00")),
+                (dt("2023-01-15 00:00:00"), dt("2023-01-22 00:00:00")),
+                (dt("2023-01-22 00:00:00"), dt("2023-01-29 00:00:00")),
+                (dt("2023-01-29 00:00:00"), dt("2023-02-01 00:00:00")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_cap_duration_descending_and_short_ranges() {
+        let iter = NaiveDatetimeRangeIterator::new(
+            dt("2023-01-01 00:00:00"),
+            dt("2023-01-01 05:00:00"),
+            Duration::hours(-3),
+        )
+        .unwrap();
+
+        let ranges: Vec<_> = iter.cap_duration(Duration::hours(2)).unwrap().collect();
+        assert_eq!(
+            ranges,
+            vec![
+                (dt("2023-01-01 04:00:00"), dt("2023-01-01 05:00:00")),
+                (dt("2023-01-01 02:00:00"), dt("2023-01-01 04:00:00")),
+                (dt("2023-01-01 00:00:00"), dt("2023-01-01 02:00:00")),
+            ]
+        );
+
+        let iter = NaiveDatetimeRangeIterator::new(
+            dt("2023-01-01 00:00:00"),
+            dt("2023-01-01 05:00:00"),
+            Duration::hours(1),
+        )
+        .unwrap();
+        assert!(matches!(
+            iter.cap_duration(Duration::zero()),
+            Err(NaiveDatetimeIterError::ZeroStep)
+        ));
+    }
+
+    #[test]
+    fn test_pad_to_day_bounds() {
+        let iter = NaiveDatetimeRangeIterator::new(
+            dt("2023-01-01 09:30:00"),
+            dt("2023-01-02 17:30:00"),
+            Duration::hours(12),
+        )
+        .unwrap();
+
+        let ranges: Vec<_> = iter.pad_to_day_bounds().collect();
+        assert_eq!(
+            ranges,
+            vec![
+                (dt("2023-01-01 00:00:00"), dt("2023-01-01 21:30:00")),
+                (dt("2023-01-01 21:30:00"), dt("2023-01-02 09:30:00")),
+                (dt("2023-01-02 09:30:00"), dt("2023-01-03 00:00:00")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_pad_to_day_bounds_descending() {
+        let iter = NaiveDatetimeRangeIterator::new(
+            dt("2023-01-01 09:30:00"),
+            dt("2023-01-02 00:00:00"),
+            Duration::hours(-8),
+        )
+        .unwrap();
+
+        let ranges: Vec<_> = iter.pad_to_day_bounds().collect();
+        assert_eq!(
+            ranges,
+            vec![
+                (dt("2023-01-01 16:00:00"), dt("2023-01-02 00:00:00")),
+                (dt("2023-01-01 00:00:00"), dt("2023-01-01 16:00:00")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_spanned_years_single_year() {
+        let iter = NaiveDatetimeIterator::new(
+            dt("2023-01-01 00:00:00"),
+            dt("2023-12-31 23:59:59"),
+            Duration::days(30),
+        )
+        .unwrap();
+
+        assert_eq!(iter.spanned_years(), vec![2023]);
+    }
+
+    #[test]
+    fn test_spanned_years_multi_year() {
+        let mut iter = NaiveDatetimeIterator::new(
+            dt("2022-12-31 00:00:00"),
+            dt("2025-01-01 00:00:00"),
+            Duration::days(-1),
+        )
+        .unwrap();
+        iter.nth(10);
+
+        assert_eq!(iter.spanned_years(), vec![2022, 2023, 2024, 2025]);
+    }
+
+    #[test]
+    fn test_with_iso_weekday() {
+        let iter = NaiveDatetimeIterator::new(
+            dt("2023-01-02 12:00:00"),
+            dt("2023-01-08 12:00:00"),
+            Duration::days(1),
+        )
+        .unwrap();
+
+        let numbers: Vec<_> = iter.with_iso_weekday().map(|(_, n)| n).collect();
+        assert_eq!(numbers, vec![1, 2, 3, 4, 5, 6, 7]);
+    }
+
+    #[test]
+    fn test_spanned_months_single_month() {
+        let iter = NaiveDatetimeIterator::new(
+            dt("2023-05-01 00:00:00"),
+            dt("2023-05-31 23:59:59"),
+            Duration::hours(1),
+        )
+        .unwrap();
+
+        assert_eq!(iter.spanned_months(), vec![(2023, 5)]);
+    }
+
+    #[test]
+    fn test_spanned_months_across_years() {
+        let mut iter = NaiveDatetimeIterator::new(
+            dt("2022-11-15 00:00:00"),
+            dt("2023-02-03 00:00:00"),
+            Duration::days(-1),
+        )
+        .unwrap();
+        iter.nth(40);
+
+        assert_eq!(
+            iter.spanned_months(),
+            vec![(2022, 11), (2022, 12), (2023, 1), (2023, 2)]
+        );
+    }
+
+    #[test]
+    fn test_labeled_iso_week() {
+        let iter = NaiveDatetimeRangeIterator::new(
+            dt("2020-12-28 00:00:00"),
+            dt("2021-01-11 00:00:00"),
+            Duration::weeks(-1),
+        )
+        .unwrap();
+
+        let labeled: Vec<_> = iter.labeled(PeriodLabel::IsoWeek).collect();
+        assert_eq!(
+            labeled,
+            vec![
+                (
+                    "2021-W01".to_string(),
+                    dt("2021-01-04 00:00:00"),
+                    dt("2021-01-11 00:00:00")
+                ),
+                (
+                    "2020-W53".to_string(),
+                    dt("2020-12-28 00:00:00"),
+                    dt("2021-01-04 00:00:00")
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_labeled_month() {
+        let iter = NaiveDatetimeRangeIterator::new(
+            dt("2023-01-20 00:00:00"),
+            dt("2023-03-01 00:00:00"),
+            Duration::days(20),
+        )
+        .unwrap();
+
+        let labels: Vec<_> = iter
+            .labeled(PeriodLabel::Month)
+            .map(|(label, _, _)| label)
+            .collect();
+        assert_eq!(labels, vec!["2023-01", "2023-02"]);
+    }
+
+    #[test]
+    fn test_labeled_quarter_and_year() {
+        let ranges = || {
+            NaiveDatetimeRangeIterator::new(
+                dt("2023-03-01 00:00:00"),
+                dt("2024-01-01 00:00:00"),
+                Duration::days(100),
+            )
+            .unwrap()
+        };
+
+        let quarters: Vec<_> = ranges()
+            .labeled(PeriodLabel::Quarter)
+            .map(|(label, _, _)| label)
+            .collect();
+        assert_eq!(quarters, vec!["2023-Q1", "2023-Q2", "2023-Q3", "2023-Q4"]);
+
+        let years: Vec<_> = ranges()
+            .labeled(PeriodLabel::Year)
+            .map(|(label, _, _)| label)
+            .collect();
+        assert_eq!(years, vec!["2023"; 4]);
+    }
+
+    #[test]
+    fn test_monthly_daily_counts_hourly() {
+        let iter = NaiveDatetimeIterator::new_exclusive(
+            dt("2024-02-01 00:00:00"),
+            dt("2024-04-01 00:00:00"),
+            Duration::hours(1),
+        )
+        .unwrap();
+
+        let counts = iter.monthly_daily_counts();
+        assert_eq!(
+            counts.keys().copied().collect::<Vec<_>>(),
+            vec![(2024, 2), (2024, 3)]
+        );
+        assert_eq!(counts[&(2024, 2)], vec![24; 29]);
+        assert_eq!(counts[&(2024, 3)], vec![24; 31]);
+    }
+
+    #[test]
+    fn test_monthly_daily_counts_partial_month() {
+        let iter = NaiveDatetimeIterator::new(
+            dt("2023-04-29 18:00:00"),
+            dt("2023-05-01 00:00:00"),
+            Duration::hours(-6),
+        )
+        .unwrap();
+
+        let counts = iter.monthly_daily_counts();
+        let april = &counts[&(2023, 4)];
+        assert_eq!(april.len(), 30);
+        assert_eq!(april.iter().sum::<usize>(), 5);
+        assert_eq!((april[28], april[29]), (1, 4));
+        assert_eq!(counts[&(2023, 5)], [vec![1], vec![0; 30]].concat());
+    }
+}
@@ -0,0 +1,226 @@
+use super::naive_datetime_iter::{NaiveDatetimeIterError, NaiveDatetimeIterator};
+use super::step::Step;
+use super::util::{duration_to_nanos, nanos_to_duration};
+use chrono::NaiveDateTime;
+
+/// Resumable snapshot of a [`NaiveDatetimeIterator`]
+///
+/// Captures the iterator's construction parameters and the position it has
+/// reached from either end. Fields are private so a checkpoint can only come
+/// from [`NaiveDatetimeIterator::checkpoint`]; with the `serde` feature it can
+/// be stored or handed out as a pagination token and later passed to
+/// [`NaiveDatetimeIterator::resume`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Checkpoint {
+    start: NaiveDateTime,
+    end: NaiveDateTime,
+    step_months: i32,
+    step_nanos: i128,
+    exclusive: bool,
+    len: u128,
+    front: u128,
+    back: u128,
+    parked: u128,
+    flipped: bool,
+}
+
+impl NaiveDatetimeIterator {
+    /// Captures the current position so iteration can later be resumed
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono_kit::iter::NaiveDatetimeIterator;
+    /// use chrono::{NaiveDateTime, Duration};
+    ///
+    /// let start = NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let end = NaiveDateTime::parse_from_str("2023-01-02 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    ///
+    /// let mut iter = NaiveDatetimeIterator::new(start, end, Duration::hours(1)).unwrap();
+    /// iter.nth(9);
+    /// let checkpoint = iter.checkpoint();
+    ///
+    /// let resumed = NaiveDatetimeIterator::resume(checkpoint).unwrap();
+    /// assert_eq!(resumed.collect::<Vec<_>>(), iter.collect::<Vec<_>>());
+    /// ```
+    pub fn checkpoint(&self) -> Checkpoint {
+        let (start, end) = self.original_bounds();
+        let step = self.full_step();
+        let (front, back, parked, flipped) = self.window();
+        Checkpoint {
+            start,
+            end,
+            step_months: step.months,
+            step_nanos: duration_to_nanos(step.duration),
+            exclusive: self.is_exclusive(),
+            len: self.total_len(),
+            front,
+            back,
+            parked,
+            flipped,
+        }
+    }
+
+    /// Rebuilds an iterator from a checkpoint
+    ///
+    /// The resumed iterator yields exactly the datetimes the checkpointed one
+    /// had left, from both ends.
+    ///
+    /// # Errors
+    /// Returns `NaiveDatetimeIterError` if the checkpoint's parameters fail the
+    /// checks of [`with_step`](Self::with_step), or if its position lies
+    /// outside the grid (`InvalidCheckpoint`), which can only happen if a
+    /// stored checkpoint was tampered with.
+    pub fn resume(checkpoint: Checkpoint) -> Result<Self, NaiveDatetimeIterError> {
+        let duration = nanos_to_duration(checkpoint.step_nanos)
+            .ok_or(NaiveDatetimeIterError::InvalidCheckpoint)?;
+        let step = Step {
+            months: checkpoint.step_months,
+            duration,
+        };
+
+        let mut iter = Self::with_step(checkpoint.start, checkpoint.end, step)?;
+        if checkpoint.exclusive {
+            iter = iter.exclude_far_bound();
+        }
+        // An exclusive grid over an empty span has no points to truncate to
+        let iter = if checkpoint.len == 0 && iter.total_len() == 0 {
+            Some(iter)
+        } else {
+            iter.with_len(checkpoint.len)
+        };
+        iter.and_then(|iter| {
+            iter.with_window(
+                checkpoint.front,
+                checkpoint.back,
+                checkpoint.parked,
+                checkpoint.flipped,
+            )
+        })
+        .ok_or(NaiveDatetimeIterError::InvalidCheckpoint)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn dt(s: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    fn assert_resumes(mut iter: NaiveDatetimeIterator) {
+        let resumed = NaiveDatetimeIterator::resume(iter.checkpoint()).unwrap();
+        assert_eq!(resumed.len(), iter.len());
+        assert_eq!(
+            resumed.rev().collect::<Vec<_>>(),
+            iter.by_ref().rev().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_resume_partway() {
+        let start = dt("2023-01-01 00:00:00");
+        let end = dt("2023-01-02 01:30:00");
+
+        for step in [Duration::hours(2), Duration::hours(-2)] {
+            let mut iter = NaiveDatetimeIterator::new(start, end, step).unwrap();
+            iter.nth(4);
+            iter.next_back();
+            assert_resumes(iter);
+        }
+    }
+
+    #[test]
+    fn test_resume_calendar_exclusive_and_flipped() {
+        let step = Step::months(1) + Duration::days(1);
+        let mut iter = NaiveDatetimeIterator::with_step(
+            dt("2023-01-31 00:00:00"),
+            dt("2024-01-01 00:00:00"),
+            step,
+        )
+        .unwrap();
+        iter.nth(3);
+        assert_resumes(iter);
+
+        let mut iter = NaiveDatetimeIterator::new_exclusive(
+            dt("2023-01-01 00:00:00"),
+            dt("2023-01-01 10:00:00"),
+            Duration::hours(1),
+        )
+        .unwrap();
+        iter.nth(5);
+        iter.flip_direction();
+        iter.next();
+        assert_resumes(iter);
+    }
+
+    #[test]
+    fn test_resume_with_count() {
+        let start = dt("2023-01-01 00:00:00");
+        let end = start + Duration::nanoseconds(10);
+
+        let mut iter = NaiveDatetimeIterator::with_count(start, end, 3).unwrap();
+        iter.next();
+        assert_resumes(iter);
+    }
+
+    #[test]
+    fn test_resume_exhausted() {
+        let mut iter = NaiveDatetimeIterator::new(
+            dt("2023-01-01 00:00:00"),
+            dt("2023-01-01 02:00:00"),
+            Duration::hours(1),
+        )
+        .unwrap();
+        iter.by_ref().for_each(drop);
+
+        let mut resumed = NaiveDatetimeIterator::resume(iter.checkpoint()).unwrap();
+        assert_eq!(resumed.next(), None);
+    }
+
+    #[test]
+    fn test_resume_empty_exclusive_grid() {
+        let t = dt("2023-01-01 00:00:00");
+        let iter = NaiveDatetimeIterator::new_exclusive(t, t, Duration::hours(1)).unwrap();
+        assert_eq!(iter.len(), 0);
+        assert_resumes(iter);
+    }
+
+    #[test]
+    fn test_resume_rejects_position_outside_grid() {
+        let iter = NaiveDatetimeIterator::new(
+            dt("2023-01-01 00:00:00"),
+            dt("2023-01-01 02:00:00"),
+            Duration::hours(1),
+        )
+        .unwrap();
+        let checkpoint = Checkpoint {
+            back: 4,
+            ..iter.checkpoint()
+        };
+
+        assert!(matches!(
+            NaiveDatetimeIterator::resume(checkpoint),
+            Err(NaiveDatetimeIterError::InvalidCheckpoint)
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_json_round_trip() {
+        let mut iter = NaiveDatetimeIterator::new(
+            dt("2023-01-01 00:00:00"),
+            dt("2023-01-03 00:00:00"),
+            Duration::minutes(-45),
+        )
+        .unwrap();
+        iter.nth(10);
+
+        let json = serde_json::to_string(&iter.checkpoint()).unwrap();
+        let checkpoint: Checkpoint = serde_json::from_str(&json).unwrap();
+        assert_eq!(checkpoint, iter.checkpoint());
+        assert_resumes(iter);
+    }
+}
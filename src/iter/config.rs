@@ -0,0 +1,185 @@
+use super::naive_datetime_iter::{NaiveDatetimeIterError, NaiveDatetimeIterator};
+use super::step::{Step, parse_iso_duration};
+use chrono::{Duration, NaiveDateTime};
+
+/// Step of a [`NaiveDatetimeIterConfig`]
+///
+/// With the `serde` feature this is (de)serialized as a bare number of
+/// seconds or an ISO 8601 duration string, such as `3600` or `"PT1H"`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(untagged)
+)]
+pub enum StepConfig {
+    /// A whole number of seconds, negative for descending iteration
+    Seconds(i64),
+    /// An ISO 8601 duration, see [`parse_iso_duration`](crate::iter::parse_iso_duration)
+    Iso(String),
+}
+
+impl StepConfig {
+    /// Converts the configured step into a [`Step`]
+    fn to_step(&self) -> Result<Step, NaiveDatetimeIterError> {
+        match self {
+            StepConfig::Seconds(seconds) => Duration::try_seconds(*seconds)
+                .map(Step::from)
+                .ok_or(NaiveDatetimeIterError::StepOutOfRange { seconds: *seconds }),
+            StepConfig::Iso(s) => Ok(parse_iso_duration(s)?),
+        }
+    }
+}
+
+/// Plain-data description of a [`NaiveDatetimeIterator`]
+///
+/// Useful for persisting iteration jobs: with the `serde` feature the config
+/// can be stored as JSON or any other serde format, and the iterator rebuilt
+/// later with [`NaiveDatetimeIterator::from_config`] or `TryFrom`.
+///
+/// # Examples
+/// ```
+/// use chrono_kit::iter::{NaiveDatetimeIterConfig, NaiveDatetimeIterator, StepConfig};
+/// use chrono::NaiveDateTime;
+///
+/// let config = NaiveDatetimeIterConfig {
+///     start: NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap(),
+///     end: NaiveDateTime::parse_from_str("2023-01-02 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap(),
+///     step: StepConfig::Iso("PT6H".to_string()),
+/// };
+///
+/// let iter = NaiveDatetimeIterator::from_config(&config).unwrap();
+/// assert_eq!(iter.count(), 5);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NaiveDatetimeIterConfig {
+    /// The starting datetime (inclusive)
+    pub start: NaiveDateTime,
+    /// The ending datetime (inclusive)
+    pub end: NaiveDateTime,
+    /// The step between each datetime
+    pub step: StepConfig,
+}
+
+impl NaiveDatetimeIterator {
+    /// Builds an iterator from a config, validating it like [`with_step`](Self::with_step)
+    ///
+    /// # Errors
+    /// Returns `NaiveDatetimeIterError` if:
+    /// - the step string is not a valid ISO 8601 duration
+    /// - the step in seconds is out of range
+    /// - any check of [`with_step`](Self::with_step) fails, such as a zero step
+    ///   or `start` after `end`
+    pub fn from_config(config: &NaiveDatetimeIterConfig) -> Result<Self, NaiveDatetimeIterError> {
+        Self::with_step(config.start, config.end, config.step.to_step()?)
+    }
+}
+
+impl TryFrom<NaiveDatetimeIterConfig> for NaiveDatetimeIterator {
+    type Error = NaiveDatetimeIterError;
+
+    fn try_from(config: NaiveDatetimeIterConfig) -> Result<Self, Self::Error> {
+        Self::from_config(&config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dt(s: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    fn config(step: StepConfig) -> NaiveDatetimeIterConfig {
+        NaiveDatetimeIterConfig {
+            start: dt("2023-01-31 00:00:00"),
+            end: dt("2023-04-30 00:00:00"),
+            step,
+        }
+    }
+
+    #[test]
+    fn test_from_config_matches_new() {
+        let iter = NaiveDatetimeIterator::try_from(config(StepConfig::Seconds(-86_400))).unwrap();
+        let expected = NaiveDatetimeIterator::new(
+            dt("2023-01-31 00:00:00"),
+            dt("2023-04-30 00:00:00"),
+            Duration::days(-1),
+        )
+        .unwrap();
+        assert!(iter.grid_eq(&expected));
+
+        let months: Vec<_> =
+            NaiveDatetimeIterator::from_config(&config(StepConfig::Iso("P1M".to_string())))
+                .unwrap()
+                .collect();
+        assert_eq!(months[1], dt("2023-02-28 00:00:00"));
+        assert_eq!(months[2], dt("2023-03-31 00:00:00"));
+    }
+
+    #[test]
+    fn test_from_config_errors() {
+        assert!(matches!(
+            NaiveDatetimeIterator::from_config(&config(StepConfig::Seconds(0))),
+            Err(NaiveDatetimeIterError::ZeroStep)
+        ));
+        assert!(matches!(
+            NaiveDatetimeIterator::from_config(&config(StepConfig::Iso("PT0S".to_string()))),
+            Err(NaiveDatetimeIterError::ZeroStep)
+        ));
+        assert!(matches!(
+            NaiveDatetimeIterator::from_config(&config(StepConfig::Iso("1 hour".to_string()))),
+            Err(NaiveDatetimeIterError::InvalidIsoStep(_))
+        ));
+        assert!(matches!(
+            NaiveDatetimeIterator::from_config(&config(StepConfig::Seconds(i64::MAX))),
+            Err(NaiveDatetimeIterError::StepOutOfRange { .. })
+        ));
+
+        let reversed = NaiveDatetimeIterConfig {
+            start: dt("2023-04-30 00:00:00"),
+            end: dt("2023-01-31 00:00:00"),
+            step: StepConfig::Seconds(60),
+        };
+        assert!(matches!(
+            NaiveDatetimeIterator::try_from(reversed),
+            Err(NaiveDatetimeIterError::InvalidRange { .. })
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_json_round_trip() {
+        for step in [
+            StepConfig::Seconds(3600),
+            StepConfig::Iso("P1M".to_string()),
+        ] {
+            let config = config(step);
+            let json = serde_json::to_string(&config).unwrap();
+            let restored: NaiveDatetimeIterConfig = serde_json::from_str(&json).unwrap();
+            assert_eq!(restored, config);
+
+            let original = NaiveDatetimeIterator::from_config(&config).unwrap();
+            let rebuilt = NaiveDatetimeIterator::try_from(restored).unwrap();
+            assert!(rebuilt.grid_eq(&original));
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_json_formats() {
+        let json = r#"{"start":"2023-01-01T00:00:00","end":"2023-01-01T01:00:00","step":900}"#;
+        let config: NaiveDatetimeIterConfig = serde_json::from_str(json).unwrap();
+        assert_eq!(config.step, StepConfig::Seconds(900));
+        assert_eq!(serde_json::to_string(&config).unwrap(), json);
+
+        let json = r#"{"start":"2023-01-01T00:00:00","end":"2023-01-01T01:00:00","step":"PT0S"}"#;
+        let config: NaiveDatetimeIterConfig = serde_json::from_str(json).unwrap();
+        assert!(matches!(
+            NaiveDatetimeIterator::try_from(config),
+            Err(NaiveDatetimeIterError::ZeroStep)
+        ));
+    }
+}
@@ -0,0 +1,149 @@
+use super::naive_datetime_iter::{NaiveDatetimeIterError, NaiveDatetimeIterator};
+use chrono::{Duration, NaiveDateTime};
+
+/// A datetime range with a step that can be iterated directly
+///
+/// This is a plain `Copy` value, so it can be stored and passed around
+/// before iterating. Nothing is validated until it is turned into an iterator:
+/// [`iter`](Self::iter) returns an error for an invalid range, while the
+/// [`IntoIterator`] impl used by `for` loops panics instead.
+///
+/// # Examples
+/// ```
+/// use chrono_kit::iter::NaiveDatetimeRange;
+/// use chrono::{NaiveDateTime, Duration};
+///
+/// let start = NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+/// let range = NaiveDatetimeRange::new(start, start + Duration::hours(2), Duration::hours(1));
+///
+/// let mut count = 0;
+/// for dt in range {
+///     assert!(dt >= start);
+///     count += 1;
+/// }
+/// assert_eq!(count, 3);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NaiveDatetimeRange {
+    /// The starting datetime (inclusive)
+    pub start: NaiveDateTime,
+    /// The ending datetime (inclusive)
+    pub end: NaiveDateTime,
+    /// The duration between each step
+    pub step: Duration,
+}
+
+impl NaiveDatetimeRange {
+    /// Creates a new NaiveDatetimeRange
+    pub fn new(start: NaiveDateTime, end: NaiveDateTime, step: Duration) -> Self {
+        NaiveDatetimeRange { start, end, step }
+    }
+
+    /// Returns an iterator over the range
+    ///
+    /// # Errors
+    /// Returns `DatetimeIterError` under the same conditions as
+    /// [`NaiveDatetimeIterator::new`]
+    pub fn iter(&self) -> Result<NaiveDatetimeIterator, NaiveDatetimeIterError> {
+        NaiveDatetimeIterator::new(self.start, self.end, self.step)
+    }
+}
+
+impl TryFrom<NaiveDatetimeRange> for NaiveDatetimeIterator {
+    type Error = NaiveDatetimeIterError;
+
+    fn try_from(range: NaiveDatetimeRange) -> Result<Self, Self::Error> {
+        range.iter()
+    }
+}
+
+impl IntoIterator for NaiveDatetimeRange {
+    type Item = NaiveDateTime;
+    type IntoIter = NaiveDatetimeIterator;
+
+    /// Converts the range into a NaiveDatetimeIterator
+    ///
+    /// # Panics
+    /// Panics if the range is invalid; use [`NaiveDatetimeRange::iter`] to
+    /// handle the error instead
+    fn into_iter(self) -> Self::IntoIter {
+        match self.iter() {
+            Ok(iter) => iter,
+            Err(e) => panic!("invalid datetime range: {e}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dt(s: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    #[test]
+    fn test_for_loop_matches_iterator() {
+        let range = NaiveDatetimeRange::new(
+            dt("2023-01-01 00:00:00"),
+            dt("2023-01-01 01:00:00"),
+            Duration::minutes(20),
+        );
+
+        let mut looped = Vec::new();
+        for dt in range {
+            looped.push(dt);
+        }
+
+        let explicit: Vec<_> = NaiveDatetimeIterator::new(range.start, range.end, range.step)
+            .unwrap()
+            .collect();
+        assert_eq!(looped, explicit);
+        assert_eq!(looped.len(), 4);
+    }
+
+    #[test]
+    fn test_stored_and_reused() {
+        struct Job {
+            range: NaiveDatetimeRange,
+        }
+
+        let job = Job {
+            range: NaiveDatetimeRange::new(
+                dt("2023-01-01 00:00:00"),
+                dt("2023-01-01 02:00:00"),
+                Duration::hours(-1),
+            ),
+        };
+
+        let first: Vec<_> = job.range.into_iter().collect();
+        let second: Vec<_> = job.range.into_iter().collect();
+        assert_eq!(first, second);
+        assert_eq!(first[0], dt("2023-01-01 02:00:00"));
+    }
+
+    #[test]
+    fn test_iter_reports_invalid_range() {
+        let range = NaiveDatetimeRange::new(
+            dt("2023-01-01 00:00:00"),
+            dt("2023-01-01 01:00:00"),
+            Duration::zero(),
+        );
+        assert!(matches!(
+            range.iter(),
+            Err(NaiveDatetimeIterError::ZeroStep)
+        ));
+        assert!(NaiveDatetimeIterator::try_from(range).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid datetime range")]
+    fn test_into_iter_panics_on_invalid_range() {
+        let range = NaiveDatetimeRange::new(
+            dt("2023-01-01 01:00:00"),
+            dt("2023-01-01 00:00:00"),
+            Duration::hours(1),
+        );
+        for _ in range {}
+    }
+}
@@ -0,0 +1,116 @@
+use super::naive_datetime_range_iter::NaiveDatetimeRangeIterator;
+use chrono::NaiveDateTime;
+
+/// Marks whether an event opens or closes a range
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventKind {
+    /// The range begins at this datetime
+    RangeStart,
+    /// The range ends at this datetime
+    RangeEnd,
+}
+
+impl NaiveDatetimeRangeIterator {
+    /// Flattens the ranges into a stream of start and end events
+    ///
+    /// Each range yields a `RangeStart` event at its start followed by a
+    /// `RangeEnd` event at its end, in the order the ranges are yielded. With
+    /// `collapse_shared` set, a boundary shared by two ranges emits only the
+    /// `RangeStart` of the later-starting range, so the stream holds one event
+    /// per boundary plus a final `RangeEnd`.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono_kit::iter::{EventKind, NaiveDatetimeRangeIterator};
+    /// use chrono::{NaiveDateTime, Duration};
+    ///
+    /// let start = NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let end = NaiveDateTime::parse_from_str("2023-01-01 01:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    ///
+    /// let iter = NaiveDatetimeRangeIterator::new(start, end, Duration::hours(1)).unwrap();
+    /// let events: Vec<_> = iter.into_events(false).collect();
+    /// assert_eq!(events, vec![(start, EventKind::RangeStart), (end, EventKind::RangeEnd)]);
+    /// ```
+    pub fn into_events(
+        self,
+        collapse_shared: bool,
+    ) -> impl Iterator<Item = (NaiveDateTime, EventKind)> {
+        let mut ranges = self.peekable();
+        let mut prev_start = None;
+        let mut pending = None;
+
+        std::iter::from_fn(move || {
+            if let Some(event) = pending.take() {
+                return Some(event);
+            }
+
+            let (start, end) = ranges.next()?;
+            let shared = collapse_shared
+                && (ranges.peek().is_some_and(|&(next, _)| next == end) || prev_start == Some(end));
+            prev_start = Some(start);
+            if !shared {
+                pending = Some((end, EventKind::RangeEnd));
+            }
+            Some((start, EventKind::RangeStart))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn dt(s: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    #[test]
+    fn test_into_events_contiguous_ranges() {
+        let iter = NaiveDatetimeRangeIterator::new(
+            dt("2023-01-01 00:00:00"),
+            dt("2023-01-01 02:00:00"),
+            Duration::hours(1),
+        )
+        .unwrap();
+
+        let events: Vec<_> = iter.into_events(false).collect();
+        assert_eq!(
+            events,
+            vec![
+                (dt("2023-01-01 00:00:00"), EventKind::RangeStart),
+                (dt("2023-01-01 01:00:00"), EventKind::RangeEnd),
+                (dt("2023-01-01 01:00:00"), EventKind::RangeStart),
+                (dt("2023-01-01 02:00:00"), EventKind::RangeEnd),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_into_events_collapse_shared() {
+        let start = dt("2023-01-01 00:00:00");
+        let end = dt("2023-01-01 02:00:00");
+
+        let iter = NaiveDatetimeRangeIterator::new(start, end, Duration::hours(1)).unwrap();
+        let events: Vec<_> = iter.into_events(true).collect();
+        assert_eq!(
+            events,
+            vec![
+                (start, EventKind::RangeStart),
+                (dt("2023-01-01 01:00:00"), EventKind::RangeStart),
+                (end, EventKind::RangeEnd),
+            ]
+        );
+
+        let iter = NaiveDatetimeRangeIterator::new(start, end, Duration::hours(-1)).unwrap();
+        let events: Vec<_> = iter.into_events(true).collect();
+        assert_eq!(
+            events,
+            vec![
+                (dt("2023-01-01 01:00:00"), EventKind::RangeStart),
+                (end, EventKind::RangeEnd),
+                (start, EventKind::RangeStart),
+            ]
+        );
+    }
+}
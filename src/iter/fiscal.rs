@@ -0,0 +1,202 @@
+use super::month_iter::NaiveDateIterError;
+use chrono::{Datelike, Months, NaiveDate};
+
+/// The length of a fiscal period
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FiscalPeriod {
+    /// One calendar month
+    Month,
+    /// Three months, counted from the start of the fiscal year
+    Quarter,
+    /// Twelve months, starting at the fiscal-year start month
+    Year,
+}
+
+impl FiscalPeriod {
+    fn months(self) -> u32 {
+        match self {
+            FiscalPeriod::Month => 1,
+            FiscalPeriod::Quarter => 3,
+            FiscalPeriod::Year => 12,
+        }
+    }
+}
+
+/// Iterator over fiscal periods covering a date range
+///
+/// Periods are aligned to a fiscal year that begins on the first day of
+/// `fy_start_month`, and each is yielded as an inclusive `(first_day, last_day)`
+/// pair. The first and last periods are clipped to `start` and `end` when the
+/// range begins or ends part-way through a period.
+///
+/// # Examples
+/// ```
+/// use chrono_kit::iter::{FiscalPeriod, FiscalPeriodIterator};
+/// use chrono::NaiveDate;
+///
+/// let start = NaiveDate::from_ymd_opt(2023, 4, 1).unwrap();
+/// let end = NaiveDate::from_ymd_opt(2024, 3, 31).unwrap();
+///
+/// let mut iter = FiscalPeriodIterator::new(start, end, 4, FiscalPeriod::Quarter).unwrap();
+/// assert_eq!(iter.next(), Some((start, NaiveDate::from_ymd_opt(2023, 6, 30).unwrap())));
+/// assert_eq!(iter.count(), 3);
+/// ```
+#[derive(Debug, Clone)]
+pub struct FiscalPeriodIterator {
+    start: NaiveDate,
+    end: NaiveDate,
+    period_months: u32,
+    next_start: Option<NaiveDate>,
+}
+
+impl FiscalPeriodIterator {
+    /// Creates a new FiscalPeriodIterator
+    ///
+    /// # Arguments
+    /// * `start` - The first date covered (inclusive)
+    /// * `end` - The last date covered (inclusive)
+    /// * `fy_start_month` - The month the fiscal year starts in, between 1 and 12
+    /// * `period` - The length of each yielded period
+    ///
+    /// # Errors
+    /// Returns `NaiveDateIterError` if:
+    /// - `fy_start_month` is outside `1..=12`
+    /// - `start` is after `end`
+    pub fn new(
+        start: NaiveDate,
+        end: NaiveDate,
+        fy_start_month: u32,
+        period: FiscalPeriod,
+    ) -> Result<Self, NaiveDateIterError> {
+        if !(1..=12).contains(&fy_start_month) {
+            return Err(NaiveDateIterError::InvalidMonth {
+                month: fy_start_month,
+            });
+        }
+        if start > end {
+            return Err(NaiveDateIterError::InvalidRange { start, end });
+        }
+
+        // Step back from the month of `start` to the period boundary before it
+        let period_months = period.months();
+        let into_year = (start.month() + 12 - fy_start_month) % 12;
+        let first_month = start.with_day(1).unwrap();
+        let next_start = first_month.checked_sub_months(Months::new(into_year % period_months));
+
+        Ok(FiscalPeriodIterator {
+            start,
+            end,
+            period_months,
+            next_start,
+        })
+    }
+}
+
+impl Iterator for FiscalPeriodIterator {
+    type Item = (NaiveDate, NaiveDate);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let period_start = self.next_start.take().filter(|&dt| dt <= self.end)?;
+        let following = period_start.checked_add_months(Months::new(self.period_months));
+        let period_end = following
+            .and_then(|dt| dt.pred_opt())
+            .unwrap_or(NaiveDate::MAX);
+
+        self.next_start = following;
+        Some((period_start.max(self.start), period_end.min(self.end)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_april_start_quarters() {
+        let iter = FiscalPeriodIterator::new(
+            date(2023, 4, 1),
+            date(2024, 3, 31),
+            4,
+            FiscalPeriod::Quarter,
+        )
+        .unwrap();
+
+        let quarters: Vec<_> = iter.collect();
+        assert_eq!(
+            quarters,
+            vec![
+                (date(2023, 4, 1), date(2023, 6, 30)),
+                (date(2023, 7, 1), date(2023, 9, 30)),
+                (date(2023, 10, 1), date(2023, 12, 31)),
+                (date(2024, 1, 1), date(2024, 3, 31)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_partial_leading_and_trailing_periods() {
+        let iter = FiscalPeriodIterator::new(
+            date(2023, 2, 15),
+            date(2023, 8, 10),
+            4,
+            FiscalPeriod::Quarter,
+        )
+        .unwrap();
+
+        let quarters: Vec<_> = iter.collect();
+        assert_eq!(
+            quarters,
+            vec![
+                (date(2023, 2, 15), date(2023, 3, 31)),
+                (date(2023, 4, 1), date(2023, 6, 30)),
+                (date(2023, 7, 1), date(2023, 8, 10)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_fiscal_years_and_months() {
+        let years: Vec<_> =
+            FiscalPeriodIterator::new(date(2023, 1, 1), date(2024, 12, 31), 7, FiscalPeriod::Year)
+                .unwrap()
+                .collect();
+        assert_eq!(
+            years,
+            vec![
+                (date(2023, 1, 1), date(2023, 6, 30)),
+                (date(2023, 7, 1), date(2024, 6, 30)),
+                (date(2024, 7, 1), date(2024, 12, 31)),
+            ]
+        );
+
+        let months =
+            FiscalPeriodIterator::new(date(2024, 2, 10), date(2024, 3, 5), 4, FiscalPeriod::Month)
+                .unwrap();
+        assert_eq!(
+            months.collect::<Vec<_>>(),
+            vec![
+                (date(2024, 2, 10), date(2024, 2, 29)),
+                (date(2024, 3, 1), date(2024, 3, 5)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_invalid_arguments() {
+        let start = date(2023, 1, 1);
+        let end = date(2023, 12, 31);
+
+        assert!(matches!(
+            FiscalPeriodIterator::new(start, end, 13, FiscalPeriod::Month),
+            Err(NaiveDateIterError::InvalidMonth { month: 13 })
+        ));
+        assert!(matches!(
+            FiscalPeriodIterator::new(end, start, 4, FiscalPeriod::Month),
+            Err(NaiveDateIterError::InvalidRange { .. })
+        ));
+    }
+}
@@ -0,0 +1,147 @@
+use chrono::{Duration, NaiveDateTime};
+use thiserror::Error;
+
+/// Errors that can occur when inferring a grid from datetimes
+#[derive(Debug, Error)]
+pub enum InferError {
+    /// Returned when fewer than two datetimes are given
+    #[error("At least two datetimes are needed to infer a grid, got {count}")]
+    TooFewPoints { count: usize },
+    /// Returned when two consecutive datetimes are equal
+    #[error("Duplicate datetime {at} at index {index}")]
+    ZeroGap { index: usize, at: NaiveDateTime },
+    /// Returned when a gap differs from the first gap
+    #[error("Irregular gap at index {index}: expected {expected}, found {found}")]
+    IrregularGap {
+        index: usize,
+        expected: Duration,
+        found: Duration,
+    },
+}
+
+/// Infers the `(start, end, step)` configuration that produced `points`
+///
+/// All gaps between consecutive datetimes must equal the first gap. The
+/// result can be passed straight to
+/// [`NaiveDatetimeIterator::new`](crate::iter::NaiveDatetimeIterator::new): for
+/// descending points the step is negative and `start` is the last point.
+///
+/// # Errors
+/// Returns `InferError` if:
+/// - fewer than two points are given
+/// - two consecutive points are equal
+/// - a gap differs from the first one; `index` is the position of the point
+///   ending the first bad gap
+///
+/// # Examples
+/// ```
+/// use chrono_kit::iter::{infer_grid, NaiveDatetimeIterator};
+/// use chrono::{NaiveDateTime, Duration};
+///
+/// let start = NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+/// let points: Vec<_> = (0..4).map(|i| start + Duration::hours(i)).collect();
+///
+/// let (start, end, step) = infer_grid(&points).unwrap();
+/// assert_eq!(step, Duration::hours(1));
+/// let rebuilt: Vec<_> = NaiveDatetimeIterator::new(start, end, step).unwrap().collect();
+/// assert_eq!(rebuilt, points);
+/// ```
+pub fn infer_grid(
+    points: &[NaiveDateTime],
+) -> Result<(NaiveDateTime, NaiveDateTime, Duration), InferError> {
+    let (first, second, last) = match points {
+        [first, second, .., last] => (*first, *second, *last),
+        [first, second] => (*first, *second, *second),
+        _ => {
+            return Err(InferError::TooFewPoints {
+                count: points.len(),
+            });
+        }
+    };
+
+    let step = second - first;
+    for (index, pair) in points.windows(2).enumerate() {
+        let gap = pair[1] - pair[0];
+        if gap.is_zero() {
+            return Err(InferError::ZeroGap {
+                index: index + 1,
+                at: pair[1],
+            });
+        }
+        if gap != step {
+            return Err(InferError::IrregularGap {
+                index: index + 1,
+                expected: step,
+                found: gap,
+            });
+        }
+    }
+
+    if step > Duration::zero() {
+        Ok((first, last, step))
+    } else {
+        Ok((last, first, step))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dt(s: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    #[test]
+    fn test_uniform_grid() {
+        let points = [
+            dt("2023-01-01 00:00:00"),
+            dt("2023-01-01 00:15:00"),
+            dt("2023-01-01 00:30:00"),
+        ];
+        let grid = infer_grid(&points).unwrap();
+        assert_eq!(grid, (points[0], points[2], Duration::minutes(15)));
+    }
+
+    #[test]
+    fn test_descending_grid() {
+        let points = [dt("2023-01-03 00:00:00"), dt("2023-01-02 00:00:00")];
+        let grid = infer_grid(&points).unwrap();
+        assert_eq!(grid, (points[1], points[0], Duration::days(-1)));
+    }
+
+    #[test]
+    fn test_irregular_grid() {
+        let points = [
+            dt("2023-01-01 00:00:00"),
+            dt("2023-01-01 01:00:00"),
+            dt("2023-01-01 02:00:00"),
+            dt("2023-01-01 04:00:00"),
+            dt("2023-01-01 05:00:00"),
+        ];
+        let result = infer_grid(&points);
+        assert!(matches!(
+            result,
+            Err(InferError::IrregularGap { index: 3, expected, found })
+                if expected == Duration::hours(1) && found == Duration::hours(2)
+        ));
+    }
+
+    #[test]
+    fn test_degenerate_inputs() {
+        assert!(matches!(
+            infer_grid(&[]),
+            Err(InferError::TooFewPoints { count: 0 })
+        ));
+        assert!(matches!(
+            infer_grid(&[dt("2023-01-01 00:00:00")]),
+            Err(InferError::TooFewPoints { count: 1 })
+        ));
+
+        let points = [dt("2023-01-01 00:00:00"), dt("2023-01-01 00:00:00")];
+        assert!(matches!(
+            infer_grid(&points),
+            Err(InferError::ZeroGap { index: 1, .. })
+        ));
+    }
+}
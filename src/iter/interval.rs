@@ -0,0 +1,631 @@
+use super::naive_datetime_iter::NaiveDatetimeIterError;
+use super::naive_datetime_range_iter::NaiveDatetimeRangeIterator;
+use super::time_range::TimeRange;
+use chrono::{Duration, NaiveDateTime};
+
+/// Merges the ranges of several iterators into a minimal set of intervals
+///
+/// All ranges are swept in order of their start, and any ranges that overlap
+/// or touch are combined. The result is sorted ascending and no two returned
+/// intervals overlap or share an endpoint.
+///
+/// # Examples
+/// ```
+/// use chrono_kit::iter::{union_ranges, NaiveDatetimeRangeIterator};
+/// use chrono::{NaiveDateTime, Duration};
+///
+/// let a_start = NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+/// let b_start = NaiveDateTime::parse_from_str("2023-01-01 02:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+/// let step = Duration::hours(1);
+///
+/// let a = NaiveDatetimeRangeIterator::new(a_start, a_start + step * 3, step).unwrap();
+/// let b = NaiveDatetimeRangeIterator::new(b_start, b_start + step * 3, step).unwrap();
+/// assert_eq!(union_ranges(vec![a, b]), vec![(a_start, b_start + step * 3)]);
+/// ```
+pub fn union_ranges(iters: Vec<NaiveDatetimeRangeIterator>) -> Vec<(NaiveDateTime, NaiveDateTime)> {
+    let mut ranges: Vec<_> = iters.into_iter().flatten().collect();
+    ranges.sort_unstable();
+
+    let mut merged: Vec<(NaiveDateTime, NaiveDateTime)> = Vec::new();
+    for (start, end) in ranges {
+        match merged.last_mut() {
+            Some((_, last_end)) if start <= *last_end => *last_end = (*last_end).max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+}
+
+/// Returns the parts of `a`'s coverage not covered by `b`
+///
+/// Both iterators are first merged into minimal intervals as in
+/// [`union_ranges`], then swept together. The result is sorted ascending and
+/// no two returned intervals overlap or share an endpoint.
+///
+/// # Examples
+/// ```
+/// use chrono_kit::iter::{range_difference, NaiveDatetimeRangeIterator};
+/// use chrono::{NaiveDateTime, Duration};
+///
+/// let start = NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+/// let hour = Duration::hours(1);
+///
+/// let a = NaiveDatetimeRangeIterator::new(start, start + hour * 4, hour).unwrap();
+/// let b = NaiveDatetimeRangeIterator::new(start + hour, start + hour * 2, hour).unwrap();
+/// assert_eq!(
+///     range_difference(a, b),
+///     vec![(start, start + hour), (start + hour * 2, start + hour * 4)]
+/// );
+/// ```
+pub fn range_difference(
+    a: NaiveDatetimeRangeIterator,
+    b: NaiveDatetimeRangeIterator,
+) -> Vec<(NaiveDateTime, NaiveDateTime)> {
+    let covered = union_ranges(vec![a]);
+    let removed = union_ranges(vec![b]);
+
+    let mut difference = Vec::new();
+    let mut next_removed = 0;
+    for (start, end) in covered {
+        while removed
+            .get(next_removed)
+            .is_some_and(|&(_, removed_end)| removed_end <= start)
+        {
+            next_removed += 1;
+        }
+
+        let mut cursor = start;
+        for &(removed_start, removed_end) in &removed[next_removed..] {
+            if removed_start >= end {
+                break;
+            }
+            if removed_start > cursor {
+                difference.push((cursor, removed_start));
+            }
+            cursor = cursor.max(removed_end);
+        }
+        if cursor < end {
+            difference.push((cursor, end));
+        }
+    }
+    difference
+}
+
+/// Yields the ranges between consecutive breakpoints
+///
+/// Produces `(points[i], points[i + 1])` for every adjacent pair, so `n`
+/// breakpoints give `n - 1` ranges. Fewer than two breakpoints give no ranges.
+///
+/// # Errors
+/// Returns `NaiveDatetimeIterError::InvalidRange` for the first adjacent pair
+/// that is out of ascending order.
+///
+/// # Examples
+/// ```
+/// use chrono_kit::iter::ranges_from_breakpoints;
+/// use chrono::{NaiveDateTime, Duration};
+///
+/// let start = NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+/// let points = [start, start + Duration::hours(1), start + Duration::hours(3)];
+///
+/// let ranges: Vec<_> = ranges_from_breakpoints(&points).unwrap().collect();
+/// assert_eq!(ranges, vec![(points[0], points[1]), (points[1], points[2])]);
+/// ```
+pub fn ranges_from_breakpoints(
+    points: &[NaiveDateTime],
+) -> Result<impl Iterator<Item = (NaiveDateTime, NaiveDateTime)>, NaiveDatetimeIterError> {
+    if let Some(pair) = points.windows(2).find(|pair| pair[0] > pair[1]) {
+        return Err(NaiveDatetimeIterError::InvalidRange {
+            start: pair[0],
+            end: pair[1],
+        });
+    }
+    Ok(points.windows(2).map(|pair| (pair[0], pair[1])))
+}
+
+/// Returns the gaps within `window` not covered by any busy range
+///
+/// Busy ranges may come in any order and may overlap, so the ranges of a
+/// [`NaiveDatetimeRangeIterator`] in either direction, or the output of
+/// [`union_ranges`], can be passed directly. Parts of busy ranges outside
+/// `window` are ignored. The gaps are returned ascending and are empty when
+/// `window` is fully covered.
+///
+/// # Examples
+/// ```
+/// use chrono_kit::iter::{free_slots, TimeRange};
+/// use chrono::{NaiveDateTime, Duration};
+///
+/// let nine = NaiveDateTime::parse_from_str("2023-01-02 09:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+/// let hour = Duration::hours(1);
+/// let busy = [(nine + hour, nine + hour * 2)];
+///
+/// let free = free_slots(busy, TimeRange::new(nine, nine + hour * 8));
+/// assert_eq!(free, vec![(nine, nine + hour), (nine + hour * 2, nine + hour * 8)]);
+/// ```
+pub fn free_slots<I>(busy: I, window: TimeRange) -> Vec<(NaiveDateTime, NaiveDateTime)>
+where
+    I: IntoIterator<Item = (NaiveDateTime, NaiveDateTime)>,
+{
+    let mut busy: Vec<_> = busy.into_iter().collect();
+    busy.sort_unstable();
+
+    let mut free = Vec::new();
+    let mut cursor = window.start;
+    for (start, end) in busy {
+        if start >= window.end {
+            break;
+        }
+        if start > cursor {
+            free.push((cursor, start));
+        }
+        cursor = cursor.max(end);
+    }
+    if cursor < window.end {
+        free.push((cursor, window.end));
+    }
+    free
+}
+
+/// Returns `true` if consecutive ranges tile an interval with no gaps or overlaps
+///
+/// Ranges may run either ascending (each range's end is the next one's start)
+/// or descending (each range's start is the next one's end), but the whole
+/// sequence must follow one direction. Empty and single-range sequences are
+/// contiguous.
+///
+/// # Examples
+/// ```
+/// use chrono_kit::iter::{is_contiguous, NaiveDatetimeRangeIterator};
+/// use chrono::{NaiveDateTime, Duration};
+///
+/// let start = NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+/// let end = NaiveDateTime::parse_from_str("2023-01-01 04:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+///
+/// let iter = NaiveDatetimeRangeIterator::new(start, end, Duration::hours(1)).unwrap();
+/// assert!(!is_contiguous(iter.enumerate().filter(|(i, _)| *i != 1).map(|(_, r)| r)));
+/// ```
+pub fn is_contiguous<I>(ranges: I) -> bool
+where
+    I: IntoIterator<Item = (NaiveDateTime, NaiveDateTime)>,
+{
+    let mut ranges = ranges.into_iter();
+    let Some(mut prev) = ranges.next() else {
+        return true;
+    };
+    let mut asc = None;
+
+    for range in ranges {
+        let step_asc = if prev.1 == range.0 {
+            true
+        } else if range.1 == prev.0 {
+            false
+        } else {
+            return false;
+        };
+        if *asc.get_or_insert(step_asc) != step_asc {
+            return false;
+        }
+        prev = range;
+    }
+    true
+}
+
+/// Returns the total time between consecutive ranges that do not touch
+///
+/// Each pair of neighbouring ranges contributes the gap between them, in
+/// whichever order they run, so ascending and descending sequences give the
+/// same result. Touching or overlapping neighbours contribute nothing, and
+/// contiguous output is zero.
+///
+/// # Examples
+/// ```
+/// use chrono_kit::iter::{total_idle, NaiveDatetimeRangeIterator};
+/// use chrono::{NaiveDateTime, Duration};
+///
+/// let start = NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+/// let end = NaiveDateTime::parse_from_str("2023-01-01 04:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+///
+/// let iter = NaiveDatetimeRangeIterator::new(start, end, Duration::hours(1)).unwrap();
+/// let every_other = iter.enumerate().filter(|(i, _)| i % 2 == 0).map(|(_, r)| r);
+/// assert_eq!(total_idle(every_other), Duration::hours(1));
+/// ```
+pub fn total_idle<I>(ranges: I) -> Duration
+where
+    I: IntoIterator<Item = (NaiveDateTime, NaiveDateTime)>,
+{
+    let mut ranges = ranges.into_iter();
+    let Some(mut prev) = ranges.next() else {
+        return Duration::zero();
+    };
+
+    let mut idle = Duration::zero();
+    for range in ranges {
+        if range.0 > prev.1 {
+            idle += range.0 - prev.1;
+        } else if prev.0 > range.1 {
+            idle += prev.0 - range.1;
+        }
+        prev = range;
+    }
+    idle
+}
+
+/// Identifies which input of [`interleave_ranges`] a range came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Source {
+    /// The first iterator
+    A,
+    /// The second iterator
+    B,
+}
+
+/// Merges the ranges of two iterators into one timeline tagged by source
+///
+/// Ranges are ordered by start, then by end, then with [`Source::A`] before
+/// [`Source::B`], regardless of either iterator's step direction. Unlike
+/// [`union_ranges`], overlapping ranges are kept separate.
+///
+/// # Examples
+/// ```
+/// use chrono_kit::iter::{interleave_ranges, NaiveDatetimeRangeIterator, Source};
+/// use chrono::{NaiveDateTime, Duration};
+///
+/// let start = NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+/// let hour = Duration::hours(1);
+///
+/// let a = NaiveDatetimeRangeIterator::new(start, start + hour * 4, hour * 2).unwrap();
+/// let b = NaiveDatetimeRangeIterator::new(start + hour, start + hour * 2, hour).unwrap();
+/// let sources: Vec<_> = interleave_ranges(a, b).map(|(source, _)| source).collect();
+/// assert_eq!(sources, vec![Source::A, Source::B, Source::A]);
+/// ```
+pub fn interleave_ranges(
+    a: NaiveDatetimeRangeIterator,
+    b: NaiveDatetimeRangeIterator,
+) -> impl Iterator<Item = (Source, (NaiveDateTime, NaiveDateTime))> {
+    let mut ranges: Vec<_> = a
+        .map(|range| (Source::A, range))
+        .chain(b.map(|range| (Source::B, range)))
+        .collect();
+    ranges.sort_unstable_by_key(|&(source, (start, end))| (start, end, source));
+    ranges.into_iter()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dt(s: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    fn ranges(start: &str, end: &str, step: Duration) -> NaiveDatetimeRangeIterator {
+        NaiveDatetimeRangeIterator::new(dt(start), dt(end), step).unwrap()
+    }
+
+    #[test]
+    fn test_union_overlapping() {
+        let a = ranges(
+            "2023-01-01 00:00:00",
+            "2023-01-01 03:00:00",
+            Duration::hours(1),
+        );
+        let b = ranges(
+            "2023-01-01 02:30:00",
+            "2023-01-01 05:00:00",
+            Duration::minutes(-30),
+        );
+
+        assert_eq!(
+            union_ranges(vec![a, b]),
+            vec![(dt("2023-01-01 00:00:00"), dt("2023-01-01 05:00:00"))]
+        );
+    }
+
+    #[test]
+    fn test_union_disjoint_and_adjacent() {
+        let a = ranges(
+            "2023-01-01 00:00:00",
+            "2023-01-01 02:00:00",
+            Duration::hours(1),
+        );
+        let b = ranges(
+            "2023-01-01 06:00:00",
+            "2023-01-01 08:00:00",
+            Duration::hours(2),
+        );
+        let c = ranges(
+            "2023-01-01 02:00:00",
+            "2023-01-01 03:00:00",
+            Duration::hours(1),
+        );
+        let d = ranges(
+            "2023-01-01 06:30:00",
+            "2023-01-01 07:00:00",
+            Duration::minutes(10),
+        );
+
+        assert_eq!(
+            union_ranges(vec![a, b, c, d]),
+            vec![
+                (dt("2023-01-01 00:00:00"), dt("2023-01-01 03:00:00")),
+                (dt("2023-01-01 06:00:00"), dt("2023-01-01 08:00:00")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_ranges_from_sorted_breakpoints() {
+        let points = [
+            dt("2023-01-01 00:00:00"),
+            dt("2023-01-01 00:10:00"),
+            dt("2023-01-01 02:00:00"),
+            dt("2023-01-03 00:00:00"),
+        ];
+
+        let ranges: Vec<_> = ranges_from_breakpoints(&points).unwrap().collect();
+        assert_eq!(
+            ranges,
+            vec![
+                (points[0], points[1]),
+                (points[1], points[2]),
+                (points[2], points[3]),
+            ]
+        );
+        assert!(is_contiguous(ranges));
+        assert_eq!(ranges_from_breakpoints(&points[..1]).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn test_ranges_from_unsorted_breakpoints() {
+        let points = [
+            dt("2023-01-01 00:00:00"),
+            dt("2023-01-01 02:00:00"),
+            dt("2023-01-01 01:00:00"),
+        ];
+
+        let result = ranges_from_breakpoints(&points);
+        assert!(matches!(
+            result,
+            Err(NaiveDatetimeIterError::InvalidRange { start, end })
+                if start == points[1] && end == points[2]
+        ));
+    }
+
+    #[test]
+    fn test_is_contiguous_mixed_directions() {
+        let forward = vec![
+            (dt("2023-01-01 00:00:00"), dt("2023-01-01 01:00:00")),
+            (dt("2023-01-01 01:00:00"), dt("2023-01-01 02:00:00")),
+        ];
+        assert!(is_contiguous(forward.clone()));
+
+        let mut mixed = forward;
+        mixed.push((dt("2023-01-01 00:00:00"), dt("2023-01-01 01:00:00")));
+        assert!(!is_contiguous(mixed));
+        assert!(is_contiguous(Vec::new()));
+    }
+
+    #[test]
+    fn test_union_empty() {
+        assert!(union_ranges(Vec::new()).is_empty());
+    }
+
+    #[test]
+    fn test_free_slots_start_middle_end() {
+        let morning = ranges(
+            "2023-01-02 09:00:00",
+            "2023-01-02 11:00:00",
+            Duration::minutes(30),
+        );
+        let afternoon = ranges(
+            "2023-01-02 13:00:00",
+            "2023-01-02 15:30:00",
+            Duration::hours(-1),
+        );
+        let window = TimeRange::new(dt("2023-01-02 08:00:00"), dt("2023-01-02 17:00:00"));
+
+        let busy = morning.chain(afternoon);
+        assert_eq!(
+            free_slots(busy, window),
+            vec![
+                (dt("2023-01-02 08:00:00"), dt("2023-01-02 09:00:00")),
+                (dt("2023-01-02 11:00:00"), dt("2023-01-02 13:00:00")),
+                (dt("2023-01-02 15:30:00"), dt("2023-01-02 17:00:00")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_free_slots_clipped_to_window() {
+        let busy = ranges(
+            "2023-01-02 07:00:00",
+            "2023-01-02 10:00:00",
+            Duration::hours(1),
+        );
+        let window = TimeRange::new(dt("2023-01-02 08:00:00"), dt("2023-01-02 12:00:00"));
+        assert_eq!(
+            free_slots(busy, window),
+            vec![(dt("2023-01-02 10:00:00"), dt("2023-01-02 12:00:00"))]
+        );
+
+        let busy = ranges(
+            "2023-01-02 07:00:00",
+            "2023-01-02 13:00:00",
+            Duration::hours(2),
+        );
+        assert!(free_slots(busy, window).is_empty());
+        assert_eq!(free_slots([], window), vec![(window.start, window.end)]);
+    }
+
+    #[test]
+    fn test_range_difference_partial_cover() {
+        let a = ranges(
+            "2023-01-01 00:00:00",
+            "2023-01-01 06:00:00",
+            Duration::hours(1),
+        );
+        let b = ranges(
+            "2023-01-01 01:30:00",
+            "2023-01-01 03:00:00",
+            Duration::minutes(-30),
+        );
+
+        assert_eq!(
+            range_difference(a, b),
+            vec![
+                (dt("2023-01-01 00:00:00"), dt("2023-01-01 01:30:00")),
+                (dt("2023-01-01 03:00:00"), dt("2023-01-01 06:00:00")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_range_difference_edges() {
+        let a = || {
+            ranges(
+                "2023-01-01 02:00:00",
+                "2023-01-01 04:00:00",
+                Duration::hours(1),
+            )
+        };
+
+        let overhanging = ranges(
+            "2023-01-01 00:00:00",
+            "2023-01-01 03:00:00",
+            Duration::hours(3),
+        );
+        assert_eq!(
+            range_difference(a(), overhanging),
+            vec![(dt("2023-01-01 03:00:00"), dt("2023-01-01 04:00:00"))]
+        );
+
+        let disjoint = ranges(
+            "2023-01-01 05:00:00",
+            "2023-01-01 06:00:00",
+            Duration::hours(1),
+        );
+        assert_eq!(
+            range_difference(a(), disjoint),
+            vec![(dt("2023-01-01 02:00:00"), dt("2023-01-01 04:00:00"))]
+        );
+
+        let covering = ranges(
+            "2023-01-01 00:00:00",
+            "2023-01-01 08:00:00",
+            Duration::hours(2),
+        );
+        assert!(range_difference(a(), covering).is_empty());
+    }
+
+    #[test]
+    fn test_total_idle_contiguous() {
+        let iter = ranges(
+            "2023-01-01 00:00:00",
+            "2023-01-01 05:30:00",
+            Duration::hours(-1),
+        );
+        assert_eq!(total_idle(iter), Duration::zero());
+        assert_eq!(total_idle(Vec::new()), Duration::zero());
+    }
+
+    #[test]
+    fn test_total_idle_spaced() {
+        let spaced = vec![
+            (dt("2023-01-01 09:00:00"), dt("2023-01-01 10:00:00")),
+            (dt("2023-01-01 10:30:00"), dt("2023-01-01 12:00:00")),
+            (dt("2023-01-01 11:00:00"), dt("2023-01-01 13:00:00")),
+            (dt("2023-01-01 15:00:00"), dt("2023-01-01 16:00:00")),
+        ];
+        assert_eq!(total_idle(spaced.clone()), Duration::minutes(150));
+        assert_eq!(total_idle(spaced.into_iter().rev()), Duration::minutes(150));
+    }
+
+    #[test]
+    fn test_interleave_ranges_alternating() {
+        let a = ranges(
+            "2023-01-01 00:00:00",
+            "2023-01-01 04:00:00",
+            Duration::hours(2),
+        );
+        let b = ranges(
+            "2023-01-01 01:00:00",
+            "2023-01-01 05:00:00",
+            Duration::hours(-2),
+        );
+
+        let merged: Vec<_> = interleave_ranges(a, b).collect();
+        assert_eq!(
+            merged,
+            vec![
+                (
+                    Source::A,
+                    (dt("2023-01-01 00:00:00"), dt("2023-01-01 02:00:00"))
+                ),
+                (
+                    Source::B,
+                    (dt("2023-01-01 01:00:00"), dt("2023-01-01 03:00:00"))
+                ),
+                (
+                    Source::A,
+                    (dt("2023-01-01 02:00:00"), dt("2023-01-01 04:00:00"))
+                ),
+                (
+                    Source::B,
+                    (dt("2023-01-01 03:00:00"), dt("2023-01-01 05:00:00"))
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_interleave_ranges_nested_and_ties() {
+        let a = ranges(
+            "2023-01-01 00:00:00",
+            "2023-01-01 04:00:00",
+            Duration::hours(4),
+        );
+        let b = ranges(
+            "2023-01-01 00:00:00",
+            "2023-01-01 04:00:00",
+            Duration::hours(1),
+        );
+
+        let merged: Vec<_> = interleave_ranges(a, b).collect();
+        assert_eq!(
+            merged,
+            vec![
+                (
+                    Source::B,
+                    (dt("2023-01-01 00:00:00"), dt("2023-01-01 01:00:00"))
+                ),
+                (
+                    Source::A,
+                    (dt("2023-01-01 00:00:00"), dt("2023-01-01 04:00:00"))
+                ),
+                (
+                    Source::B,
+                    (dt("2023-01-01 01:00:00"), dt("2023-01-01 02:00:00"))
+                ),
+                (
+                    Source::B,
+                    (dt("2023-01-01 02:00:00"), dt("2023-01-01 03:00:00"))
+                ),
+                (
+                    Source::B,
+                    (dt("2023-01-01 03:00:00"), dt("2023-01-01 04:00:00"))
+                ),
+            ]
+        );
+
+        let same = ranges(
+            "2023-01-01 00:00:00",
+            "2023-01-01 01:00:00",
+            Duration::hours(1),
+        );
+        let sources: Vec<_> = interleave_ranges(same.clone(), same)
+            .map(|(source, _)| source)
+            .collect();
+        assert_eq!(sources, vec![Source::A, Source::B]);
+    }
+}
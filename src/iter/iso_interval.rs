@@ -0,0 +1,199 @@
+use super::naive_datetime_iter::{NaiveDatetimeIterError, NaiveDatetimeIterator};
+use super::step::parse_iso_duration;
+use chrono::NaiveDateTime;
+
+impl NaiveDatetimeIterator {
+    /// Parses an ISO 8601 interval string into an iterator
+    ///
+    /// Three notations are understood, with datetimes written like
+    /// `2023-01-01T00:00:00` and durations like `PT1H` (see
+    /// [`parse_iso_duration`](crate::iter::parse_iso_duration)):
+    /// - `start/end/step` iterates from `start` to `end` by `step`, exactly
+    ///   like [`with_step`](Self::with_step)
+    /// - `Rn/start/duration` repeats the interval `n` times, yielding the
+    ///   `n + 1` boundaries `start`, `start + duration`, ..., `start + n * duration`
+    /// - `start/duration` is a single interval, the same as `R1/start/duration`
+    ///
+    /// A negative duration in the repeating notations steps backwards from
+    /// `start`, which is then yielded first. Unbounded repetition (`R/...`)
+    /// is rejected since it describes an endless series.
+    ///
+    /// # Errors
+    /// Returns `NaiveDatetimeIterError` if:
+    /// - the string does not match one of the notations, or a datetime or
+    ///   repeat count in it is malformed (`ParseError`)
+    /// - a duration is not a valid ISO 8601 duration (`InvalidIsoStep`)
+    /// - the resulting range fails the checks of [`with_step`](Self::with_step)
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono_kit::iter::NaiveDatetimeIterator;
+    ///
+    /// let iter = NaiveDatetimeIterator::parse_iso8601_interval("R5/2023-01-01T00:00:00/PT1H").unwrap();
+    /// let points: Vec<_> = iter.map(|dt| dt.to_string()).collect();
+    /// assert_eq!(points.len(), 6);
+    /// assert_eq!(points[5], "2023-01-01 05:00:00");
+    /// ```
+    pub fn parse_iso8601_interval(s: &str) -> Result<Self, NaiveDatetimeIterError> {
+        let error = |reason| NaiveDatetimeIterError::ParseError {
+            input: s.to_string(),
+            reason,
+        };
+        let datetime = |part: &str| {
+            part.parse::<NaiveDateTime>()
+                .map_err(|_| error("invalid datetime"))
+        };
+
+        let parts: Vec<&str> = s.trim().split('/').collect();
+        let (repetitions, start, duration) = match parts.as_slice() {
+            [start, end, step] if !start.starts_with('R') => {
+                return Self::with_step(
+                    datetime(start)?,
+                    datetime(end)?,
+                    parse_iso_duration(step)?,
+                );
+            }
+            [repeat, start, duration] => {
+                let count = &repeat[1..];
+                if count.is_empty() {
+                    return Err(error("unbounded repetition is not supported"));
+                }
+                let count = count
+                    .parse::<u32>()
+                    .map_err(|_| error("invalid repeat count"))?;
+                (count, start, duration)
+            }
+            [start, duration] => (1, start, duration),
+            _ => {
+                return Err(error(
+                    "expected start/end/step, Rn/start/duration, or start/duration",
+                ));
+            }
+        };
+
+        let start = datetime(start)?;
+        let step = parse_iso_duration(duration)?;
+        let end = step
+            .apply(start, repetitions.into())
+            .ok_or_else(|| error("repetitions run out of range"))?;
+        if step.is_positive() || step.is_zero() {
+            Self::with_step(start, end, step)
+        } else {
+            Self::with_step(end, start, step)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dt(s: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    #[test]
+    fn test_start_end_step() {
+        let iter = NaiveDatetimeIterator::parse_iso8601_interval(
+            "2023-01-01T00:00:00/2023-01-03T00:00:00/P1D",
+        )
+        .unwrap();
+        assert_eq!(
+            iter.collect::<Vec<_>>(),
+            vec![
+                dt("2023-01-01 00:00:00"),
+                dt("2023-01-02 00:00:00"),
+                dt("2023-01-03 00:00:00"),
+            ]
+        );
+
+        let iter = NaiveDatetimeIterator::parse_iso8601_interval(
+            "2023-01-31T00:00:00/2023-03-31T00:00:00/-P1M",
+        )
+        .unwrap();
+        assert_eq!(
+            iter.collect::<Vec<_>>(),
+            vec![
+                dt("2023-03-31 00:00:00"),
+                dt("2023-02-28 00:00:00"),
+                dt("2023-01-31 00:00:00"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_repeating() {
+        let points: Vec<_> =
+            NaiveDatetimeIterator::parse_iso8601_interval("R5/2023-01-01T00:00:00/PT1H")
+                .unwrap()
+                .collect();
+        assert_eq!(points.len(), 6);
+        assert_eq!(points[0], dt("2023-01-01 00:00:00"));
+        assert_eq!(points[5], dt("2023-01-01 05:00:00"));
+
+        let points: Vec<_> =
+            NaiveDatetimeIterator::parse_iso8601_interval("R2/2023-01-01T00:00:00/-PT30M")
+                .unwrap()
+                .collect();
+        assert_eq!(
+            points,
+            vec![
+                dt("2023-01-01 00:00:00"),
+                dt("2022-12-31 23:30:00"),
+                dt("2022-12-31 23:00:00"),
+            ]
+        );
+
+        let points: Vec<_> =
+            NaiveDatetimeIterator::parse_iso8601_interval("R0/2023-01-01T00:00:00/PT1H")
+                .unwrap()
+                .collect();
+        assert_eq!(points, vec![dt("2023-01-01 00:00:00")]);
+    }
+
+    #[test]
+    fn test_start_duration() {
+        let points: Vec<_> =
+            NaiveDatetimeIterator::parse_iso8601_interval("2023-01-01T12:00:00.5/PT90M")
+                .unwrap()
+                .collect();
+        let start = dt("2023-01-01 12:00:00") + chrono::Duration::milliseconds(500);
+        assert_eq!(points, vec![start, start + chrono::Duration::minutes(90)]);
+    }
+
+    #[test]
+    fn test_malformed() {
+        for input in [
+            "",
+            "2023-01-01T00:00:00",
+            "R/2023-01-01T00:00:00/PT1H",
+            "Rx/2023-01-01T00:00:00/PT1H",
+            "R-1/2023-01-01T00:00:00/PT1H",
+            "2023-13-01T00:00:00/PT1H",
+            "R1/2023-01-01T00:00:00/PT1H/PT1H",
+        ] {
+            assert!(
+                matches!(
+                    NaiveDatetimeIterator::parse_iso8601_interval(input),
+                    Err(NaiveDatetimeIterError::ParseError { .. })
+                ),
+                "{input:?} should fail to parse"
+            );
+        }
+
+        assert!(matches!(
+            NaiveDatetimeIterator::parse_iso8601_interval("R3/2023-01-01T00:00:00/1H"),
+            Err(NaiveDatetimeIterError::InvalidIsoStep(_))
+        ));
+        assert!(matches!(
+            NaiveDatetimeIterator::parse_iso8601_interval(
+                "2023-01-03T00:00:00/2023-01-01T00:00:00/P1D"
+            ),
+            Err(NaiveDatetimeIterError::InvalidRange { .. })
+        ));
+        assert!(matches!(
+            NaiveDatetimeIterator::parse_iso8601_interval("R3/2023-01-01T00:00:00/PT0S"),
+            Err(NaiveDatetimeIterError::ZeroStep)
+        ));
+    }
+}
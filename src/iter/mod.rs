@@ -3,15 +3,107 @@
 //! This module provides iterators for working with datetime ranges:
 //! - `NaiveDatetimeIterator`: Iterates through individual datetimes
 //! - `NaiveDatetimeRangeIterator`: Iterates through datetime ranges
+//! - `NaiveDateIterator` / `NaiveDateRangeIterator`: Date-only counterparts stepping by whole days
+//! - `DateTimeIterator`: Iterates through timezone-aware datetimes in elapsed time across DST changes
+//! - `NaiveTimeIterator`: Iterates through clock times, optionally wrapping past midnight
+//! - `SlidingWindowIterator`: Iterates through fixed-length, possibly overlapping windows advancing by a stride
+//! - `VariableStepIterator`: Iterates through datetimes following a repeating step pattern
+//! - `MonthlyAnchoredIterator`: Iterates through dates on a fixed day every few months
+//! - `NaiveDateMonthIterator`: Iterates through dates a fixed number of calendar months apart
+//! - `BusinessDayIterator`: Iterates through the business days between two dates
+//! - `ShiftIterator`: Iterates through working shifts on each day, including overnight shifts
+//! - `FiscalPeriodIterator`: Iterates through fiscal months, quarters, or years
+//!
+//! A `NaiveDatetimeRange` value of start, end and step can be stored and iterated with `for`.
+//! Ranges can also be handled as `TimeRange` values, a half-open `[start, end)` struct,
+//! or flattened into `(datetime, EventKind)` start and end events.
 //!
 //! Both iterators support forward and reverse iteration:
 //! - Forward iteration: Use positive step duration
 //! - Reverse iteration: Use negative step duration
 //!
 //! See the individual iterator documentation for examples.
+//!
+//! Grid helpers:
+//! - `aligned_start`: Finds the first point of an epoch-anchored grid at or after a datetime
+//! - `next_grid_point`: Finds the first point of an unbounded grid strictly after a datetime
+//! - `quarter_hour_slots`: Yields 15-minute ranges aligned to the clock
+//! - `parse_iso_duration`: Parses an ISO 8601 duration into a `Step` with calendar months
+//! - `Checkpoint`: Resumable snapshot of an iterator's position, serializable with the `serde` feature
+//! - `NaiveDatetimeIterConfig`: Plain-data iterator description, serializable with the `serde` feature
+//! - `NaiveDatetimeIterator::parse_iso8601_interval`: Parses ISO 8601 interval strings such as `R5/2023-01-01T00:00:00/PT1H`
+//! - `parse_schedule`: Parses a schedule such as `2023-01-01..2023-02-01 by 1d skip weekends`
+//! - `points_to_ranges` / `ranges_to_points`: Convert between point and range counts
+//! - `utc_series`: Returns a fixed number of evenly spaced UTC timestamps
+//! - `infer_grid`: Recovers the `(start, end, step)` of a uniformly spaced slice of datetimes
+//!
+//! Calendar helpers:
+//! - `end_of_day`: Yields the last instant of each day in a date range
+//! - `add_business_days`: Moves a date by a number of business days
+//! - `next_business_day`: Finds the next business day under a `HolidayCalendar`
+//! - `dst_anomalies`: Finds grid points that are ambiguous or nonexistent in a time zone (requires `chrono-tz`)
+//! - `overlap_hours`: Finds the overlap of two time zones' working hours (requires `chrono-tz`)
+//! - `every_nth_business_day`: Yields dates spaced a fixed number of business days apart
+//!
+//! Interval helpers:
+//! - `union_ranges`: Merges the ranges of several iterators into minimal intervals
+//! - `range_difference`: Finds the parts of one iterator's coverage not covered by another
+//! - `ranges_from_breakpoints`: Yields the ranges between consecutive breakpoints
+//! - `free_slots`: Finds the gaps within a window not covered by busy ranges
+//! - `is_contiguous`: Checks that a sequence of ranges has no gaps or overlaps
+//! - `total_idle`: Sums the gaps between consecutive ranges
 
+mod adapters;
+mod align;
+mod business;
+mod calendar;
+mod checkpoint;
+mod config;
+mod datetime_range;
+mod events;
+mod fiscal;
+mod infer;
+mod interval;
+mod iso_interval;
+mod month_iter;
+mod naive_date_iter;
+mod naive_date_range_iter;
 mod naive_datetime_iter;
 mod naive_datetime_range_iter;
+mod position;
+mod sampling;
+mod schedule;
+mod shift;
+mod sliding_window;
+mod step;
+mod time_iter;
+mod time_range;
+mod utc;
+mod util;
+mod variable_step_iter;
+mod zoned;
 
+pub use align::*;
+pub use business::*;
+pub use calendar::*;
+pub use checkpoint::*;
+pub use config::*;
+pub use datetime_range::*;
+pub use events::*;
+pub use fiscal::*;
+pub use infer::*;
+pub use interval::*;
+pub use month_iter::*;
+pub use naive_date_iter::*;
+pub use naive_date_range_iter::*;
 pub use naive_datetime_iter::*;
 pub use naive_datetime_range_iter::*;
+pub use schedule::*;
+pub use shift::*;
+pub use sliding_window::*;
+pub use step::*;
+pub use time_iter::*;
+pub use time_range::*;
+pub use utc::*;
+pub use variable_step_iter::*;
+pub use zoned::*;
@@ -0,0 +1,402 @@
+use chrono::{Datelike, Duration, Months, NaiveDate};
+use thiserror::Error;
+
+/// Errors that can occur when creating a date iterator
+#[derive(Debug, Error)]
+pub enum NaiveDateIterError {
+    /// Returned when the step is zero
+    #[error("Step cannot be zero")]
+    ZeroStep,
+    /// Returned when start date is after end date
+    #[error("Invalid range: start {start} must be before end {end}")]
+    InvalidRange { start: NaiveDate, end: NaiveDate },
+    /// Returned when a day of month is outside `1..=31`
+    #[error("Invalid day of month: {day} must be between 1 and 31")]
+    InvalidDay { day: u32 },
+    /// Returned when a step is not a whole number of days
+    #[error("Invalid step: {step} is not a whole number of days")]
+    FractionalDayStep { step: Duration },
+    /// Returned when a month is outside `1..=12`
+    #[error("Invalid month: {month} must be between 1 and 12")]
+    InvalidMonth { month: u32 },
+}
+
+/// Returns the last day of the month containing `date`
+fn last_day_of_month(date: NaiveDate) -> u32 {
+    let first = date.with_day(1).unwrap();
+    match first.checked_add_months(Months::new(1)) {
+        Some(next) => next.pred_opt().unwrap().day(),
+        None => 31,
+    }
+}
+
+/// Iterator that yields dates on a fixed day of the month every few months
+///
+/// Starting from the month of `start`, a date is yielded every `interval_months`
+/// months on the anchor `day`, clamped to the last day of months that are too
+/// short. Dates before `start` or after `end` are skipped.
+///
+/// # Examples
+/// ```
+/// use chrono_kit::iter::MonthlyAnchoredIterator;
+/// use chrono::NaiveDate;
+///
+/// let start = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+/// let end = NaiveDate::from_ymd_opt(2023, 12, 31).unwrap();
+///
+/// let mut iter = MonthlyAnchoredIterator::new(start, end, 3, 15).unwrap();
+/// assert_eq!(iter.next(), NaiveDate::from_ymd_opt(2023, 1, 15));
+/// assert_eq!(iter.next(), NaiveDate::from_ymd_opt(2023, 4, 15));
+/// ```
+#[derive(Debug, Clone)]
+pub struct MonthlyAnchoredIterator {
+    start: NaiveDate,
+    end: NaiveDate,
+    first_month: NaiveDate,
+    interval_months: u32,
+    day: u32,
+    index: u32,
+}
+
+impl MonthlyAnchoredIterator {
+    /// Creates a new MonthlyAnchoredIterator
+    ///
+    /// # Arguments
+    /// * `start` - The earliest date that may be yielded (inclusive)
+    /// * `end` - The latest date that may be yielded (inclusive)
+    /// * `interval_months` - The number of months between yielded dates (must be non-zero)
+    /// * `day` - The anchor day of the month, between 1 and 31
+    ///
+    /// # Errors
+    /// Returns `NaiveDateIterError` if:
+    /// - `interval_months` is zero
+    /// - `day` is outside `1..=31`
+    /// - `start` is after `end`
+    pub fn new(
+        start: NaiveDate,
+        end: NaiveDate,
+        interval_months: u32,
+        day: u32,
+    ) -> Result<Self, NaiveDateIterError> {
+        if interval_months == 0 {
+            return Err(NaiveDateIterError::ZeroStep);
+        }
+        if !(1..=31).contains(&day) {
+            return Err(NaiveDateIterError::InvalidDay { day });
+        }
+        if start > end {
+            return Err(NaiveDateIterError::InvalidRange { start, end });
+        }
+        Ok(MonthlyAnchoredIterator {
+            start,
+            end,
+            first_month: start.with_day(1).unwrap(),
+            interval_months,
+            day,
+            index: 0,
+        })
+    }
+}
+
+/// Iterator that yields dates a fixed number of calendar months apart
+///
+/// The i-th date is computed directly as `start + months * i` with
+/// `chrono::Months`, so a day missing from a short month is clamped to its last
+/// day without shifting later dates: a schedule starting on January 31 yields
+/// February 28 (or 29) and then March 31. Descending schedules built with
+/// [`years`](Self::years) count back from `end` the same way.
+///
+/// # Examples
+/// ```
+/// use chrono_kit::iter::NaiveDateMonthIterator;
+/// use chrono::NaiveDate;
+///
+/// let start = NaiveDate::from_ymd_opt(2023, 1, 31).unwrap();
+/// let end = NaiveDate::from_ymd_opt(2023, 3, 31).unwrap();
+///
+/// let mut iter = NaiveDateMonthIterator::new(start, end, 1).unwrap();
+/// assert_eq!(iter.next(), Some(start));
+/// assert_eq!(iter.next(), NaiveDate::from_ymd_opt(2023, 2, 28));
+/// assert_eq!(iter.next(), Some(end));
+/// ```
+#[derive(Debug, Clone)]
+pub struct NaiveDateMonthIterator {
+    start: NaiveDate,
+    end: NaiveDate,
+    months: u32,
+    asc: bool,
+    index: u32,
+}
+
+impl NaiveDateMonthIterator {
+    /// Creates a new NaiveDateMonthIterator
+    ///
+    /// # Arguments
+    /// * `start` - The first date yielded (inclusive)
+    /// * `end` - The latest date that may be yielded (inclusive)
+    /// * `months` - The number of months between yielded dates (must be non-zero)
+    ///
+    /// # Errors
+    /// Returns `NaiveDateIterError` if:
+    /// - `months` is zero
+    /// - `start` is after `end`
+    pub fn new(start: NaiveDate, end: NaiveDate, months: u32) -> Result<Self, NaiveDateIterError> {
+        if months == 0 {
+            return Err(NaiveDateIterError::ZeroStep);
+        }
+        if start > end {
+            return Err(NaiveDateIterError::InvalidRange { start, end });
+        }
+        Ok(NaiveDateMonthIterator {
+            start,
+            end,
+            months,
+            asc: true,
+            index: 0,
+        })
+    }
+
+    /// Creates a new NaiveDateMonthIterator stepping by whole years
+    ///
+    /// A schedule anchored on February 29 falls on February 28 in common
+    /// years and returns to February 29 in every leap year. A negative
+    /// `years` iterates from `end` back towards `start`.
+    ///
+    /// # Errors
+    /// Returns `NaiveDateIterError` if:
+    /// - `years` is zero
+    /// - `start` is after `end`
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono_kit::iter::NaiveDateMonthIterator;
+    /// use chrono::NaiveDate;
+    ///
+    /// let start = NaiveDate::from_ymd_opt(2020, 2, 29).unwrap();
+    /// let end = NaiveDate::from_ymd_opt(2021, 12, 31).unwrap();
+    ///
+    /// let mut iter = NaiveDateMonthIterator::years(start, end, 1).unwrap();
+    /// assert_eq!(iter.next(), Some(start));
+    /// assert_eq!(iter.next(), NaiveDate::from_ymd_opt(2021, 2, 28));
+    /// ```
+    pub fn years(start: NaiveDate, end: NaiveDate, years: i32) -> Result<Self, NaiveDateIterError> {
+        let mut iter = Self::new(start, end, years.unsigned_abs().saturating_mul(12))?;
+        iter.asc = years > 0;
+        Ok(iter)
+    }
+}
+
+impl Iterator for NaiveDateMonthIterator {
+    type Item = NaiveDate;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let date = self.index.checked_mul(self.months).and_then(|months| {
+            if self.asc {
+                self.start.checked_add_months(Months::new(months))
+            } else {
+                self.end.checked_sub_months(Months::new(months))
+            }
+        });
+        let date = date.filter(|date| self.start <= *date && *date <= self.end);
+        match date {
+            Some(date) => {
+                self.index += 1;
+                Some(date)
+            }
+            None => {
+                // Park the index past the end so iteration stays exhausted
+                self.index = u32::MAX;
+                None
+            }
+        }
+    }
+}
+
+impl Iterator for MonthlyAnchoredIterator {
+    type Item = NaiveDate;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let months = self.index.checked_mul(self.interval_months)?;
+            let month = self.first_month.checked_add_months(Months::new(months));
+            let date = month.and_then(|m| m.with_day(self.day.min(last_day_of_month(m))));
+            let date = match date {
+                Some(date) if date <= self.end => date,
+                _ => {
+                    // Park the index past the end so iteration stays exhausted
+                    self.index = u32::MAX;
+                    return None;
+                }
+            };
+
+            self.index += 1;
+            if date >= self.start {
+                return Some(date);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_quarterly_on_the_31st() {
+        let iter =
+            MonthlyAnchoredIterator::new(date(2023, 1, 1), date(2024, 1, 31), 3, 31).unwrap();
+
+        let dates: Vec<_> = iter.collect();
+        assert_eq!(
+            dates,
+            vec![
+                date(2023, 1, 31),
+                date(2023, 4, 30),
+                date(2023, 7, 31),
+                date(2023, 10, 31),
+                date(2024, 1, 31),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_clamps_to_february() {
+        let iter =
+            MonthlyAnchoredIterator::new(date(2023, 12, 1), date(2024, 3, 31), 1, 30).unwrap();
+
+        let dates: Vec<_> = iter.collect();
+        assert_eq!(
+            dates,
+            vec![
+                date(2023, 12, 30),
+                date(2024, 1, 30),
+                date(2024, 2, 29),
+                date(2024, 3, 30),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_skips_anchor_before_start() {
+        let mut iter =
+            MonthlyAnchoredIterator::new(date(2023, 1, 20), date(2023, 6, 30), 2, 15).unwrap();
+
+        assert_eq!(iter.next(), Some(date(2023, 3, 15)));
+        assert_eq!(iter.next(), Some(date(2023, 5, 15)));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_month_iterator_end_of_month_clamping() {
+        let iter = NaiveDateMonthIterator::new(date(2023, 1, 31), date(2023, 5, 1), 1).unwrap();
+
+        let dates: Vec<_> = iter.collect();
+        assert_eq!(
+            dates,
+            vec![
+                date(2023, 1, 31),
+                date(2023, 2, 28),
+                date(2023, 3, 31),
+                date(2023, 4, 30),
+            ]
+        );
+
+        let mut iter =
+            NaiveDateMonthIterator::new(date(2023, 2, 28), date(2023, 4, 30), 1).unwrap();
+        assert_eq!(iter.nth(1), Some(date(2023, 3, 28)));
+    }
+
+    #[test]
+    fn test_month_iterator_leap_day_start() {
+        let iter = NaiveDateMonthIterator::new(date(2024, 2, 29), date(2028, 12, 31), 12).unwrap();
+
+        let dates: Vec<_> = iter.collect();
+        assert_eq!(
+            dates,
+            vec![
+                date(2024, 2, 29),
+                date(2025, 2, 28),
+                date(2026, 2, 28),
+                date(2027, 2, 28),
+                date(2028, 2, 29),
+            ]
+        );
+
+        let mut iter = NaiveDateMonthIterator::new(date(2024, 1, 31), date(2024, 3, 1), 1).unwrap();
+        assert_eq!(iter.nth(1), Some(date(2024, 2, 29)));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_year_stepping_from_leap_day() {
+        let iter = NaiveDateMonthIterator::years(date(2020, 2, 29), date(2024, 12, 31), 1).unwrap();
+
+        let dates: Vec<_> = iter.collect();
+        assert_eq!(
+            dates,
+            vec![
+                date(2020, 2, 29),
+                date(2021, 2, 28),
+                date(2022, 2, 28),
+                date(2023, 2, 28),
+                date(2024, 2, 29),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_year_stepping_descending() {
+        let iter = NaiveDateMonthIterator::years(date(2019, 1, 1), date(2024, 2, 29), -2).unwrap();
+
+        let dates: Vec<_> = iter.collect();
+        assert_eq!(
+            dates,
+            vec![date(2024, 2, 29), date(2022, 2, 28), date(2020, 2, 29)]
+        );
+
+        assert!(matches!(
+            NaiveDateMonthIterator::years(date(2019, 1, 1), date(2024, 2, 29), 0),
+            Err(NaiveDateIterError::ZeroStep)
+        ));
+    }
+
+    #[test]
+    fn test_month_iterator_invalid_arguments() {
+        let start = date(2023, 1, 1);
+        let end = date(2023, 12, 31);
+
+        assert!(matches!(
+            NaiveDateMonthIterator::new(start, end, 0),
+            Err(NaiveDateIterError::ZeroStep)
+        ));
+        assert!(matches!(
+            NaiveDateMonthIterator::new(end, start, 1),
+            Err(NaiveDateIterError::InvalidRange { .. })
+        ));
+    }
+
+    #[test]
+    fn test_invalid_arguments() {
+        let start = date(2023, 1, 1);
+        let end = date(2023, 12, 31);
+
+        assert!(matches!(
+            MonthlyAnchoredIterator::new(start, end, 0, 15),
+            Err(NaiveDateIterError::ZeroStep)
+        ));
+        assert!(matches!(
+            MonthlyAnchoredIterator::new(start, end, 1, 32),
+            Err(NaiveDateIterError::InvalidDay { day: 32 })
+        ));
+        assert!(matches!(
+            MonthlyAnchoredIterator::new(end, start, 1, 15),
+            Err(NaiveDateIterError::InvalidRange { .. })
+        ));
+    }
+}
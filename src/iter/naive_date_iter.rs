@@ -0,0 +1,216 @@
+use super::month_iter::NaiveDateIterError;
+use super::naive_datetime_iter::NaiveDatetimeIterator;
+use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime};
+
+/// Checks that `step` and the bounds form a valid date grid
+pub(crate) fn validate_date_grid(
+    start: NaiveDate,
+    end: NaiveDate,
+    step: Duration,
+) -> Result<(), NaiveDateIterError> {
+    if step.is_zero() {
+        return Err(NaiveDateIterError::ZeroStep);
+    }
+    if step.num_seconds() % 86_400 != 0 || step.subsec_nanos() != 0 {
+        return Err(NaiveDateIterError::FractionalDayStep { step });
+    }
+    if start > end {
+        return Err(NaiveDateIterError::InvalidRange { start, end });
+    }
+    Ok(())
+}
+
+/// Returns midnight at the start of `date`
+pub(crate) fn midnight(date: NaiveDate) -> NaiveDateTime {
+    date.and_time(NaiveTime::MIN)
+}
+
+/// An iterator that yields dates between a start and end date (inclusive)
+///
+/// This is the date-only counterpart of [`NaiveDatetimeIterator`] and follows
+/// the same rules: the step may be positive or negative, and the far bound is
+/// always yielded last even if it is not a whole number of steps away.
+///
+/// # Examples
+///
+/// Forward iteration:
+/// ```
+/// use chrono_kit::iter::NaiveDateIterator;
+/// use chrono::{NaiveDate, Duration};
+///
+/// let start = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+/// let end = NaiveDate::from_ymd_opt(2023, 1, 3).unwrap();
+/// let step = Duration::days(1);
+///
+/// let mut iter = NaiveDateIterator::new(start, end, step).unwrap();
+/// assert_eq!(iter.next(), Some(start));
+/// assert_eq!(iter.next(), Some(start + step));
+/// assert_eq!(iter.next(), Some(end));
+/// assert_eq!(iter.next(), None);
+/// ```
+///
+/// Reverse iteration:
+/// ```
+/// use chrono_kit::iter::NaiveDateIterator;
+/// use chrono::{NaiveDate, Duration};
+///
+/// let start = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+/// let end = NaiveDate::from_ymd_opt(2023, 1, 3).unwrap();
+/// let step = Duration::days(-1);
+///
+/// let mut iter = NaiveDateIterator::new(start, end, step).unwrap();
+/// assert_eq!(iter.next(), Some(end));
+/// assert_eq!(iter.next(), Some(end + step));
+/// assert_eq!(iter.next(), Some(start));
+/// assert_eq!(iter.next(), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct NaiveDateIterator {
+    datetime_iter: NaiveDatetimeIterator,
+}
+
+impl NaiveDateIterator {
+    /// Creates a new NaiveDateIterator
+    ///
+    /// # Arguments
+    /// * `start` - The starting date (inclusive)
+    /// * `end` - The ending date (inclusive)
+    /// * `step` - The duration between each step (a non-zero whole number of days)
+    ///
+    /// # Errors
+    /// Returns `NaiveDateIterError` if:
+    /// - `step` is zero
+    /// - `step` is not a whole number of days
+    /// - `start` is after `end`
+    pub fn new(
+        start: NaiveDate,
+        end: NaiveDate,
+        step: Duration,
+    ) -> Result<Self, NaiveDateIterError> {
+        validate_date_grid(start, end, step)?;
+        let datetime_iter = NaiveDatetimeIterator::new(midnight(start), midnight(end), step)
+            .expect("date grid is validated");
+        Ok(NaiveDateIterator { datetime_iter })
+    }
+}
+
+impl Iterator for NaiveDateIterator {
+    type Item = NaiveDate;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.datetime_iter.next().map(|dt| dt.date())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.datetime_iter.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for NaiveDateIterator {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.datetime_iter.next_back().map(|dt| dt.date())
+    }
+}
+
+impl ExactSizeIterator for NaiveDateIterator {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_ascending_iteration() {
+        let start = date(2023, 1, 1);
+        let end = date(2023, 1, 3);
+        let step = Duration::days(1);
+
+        let mut iter = NaiveDateIterator::new(start, end, step).unwrap();
+        assert_eq!(iter.next(), Some(start));
+        assert_eq!(iter.next(), Some(start + step));
+        assert_eq!(iter.next(), Some(end));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_descending_iteration() {
+        let start = date(2023, 1, 1);
+        let end = date(2023, 1, 3);
+        let step = Duration::days(-1);
+
+        let mut iter = NaiveDateIterator::new(start, end, step).unwrap();
+        assert_eq!(iter.next(), Some(end));
+        assert_eq!(iter.next(), Some(end + step));
+        assert_eq!(iter.next(), Some(start));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_non_integer_period() {
+        let start = date(2023, 1, 1);
+        let end = date(2023, 1, 10);
+
+        let dates: Vec<_> = NaiveDateIterator::new(start, end, Duration::weeks(1))
+            .unwrap()
+            .collect();
+        assert_eq!(dates, vec![start, date(2023, 1, 8), end]);
+
+        let iter = NaiveDateIterator::new(start, end, Duration::days(-4)).unwrap();
+        assert_eq!(iter.len(), 4);
+        assert_eq!(
+            iter.rev().collect::<Vec<_>>(),
+            vec![start, date(2023, 1, 2), date(2023, 1, 6), end]
+        );
+    }
+
+    #[test]
+    fn test_single_date() {
+        let day = date(2023, 1, 1);
+
+        let mut iter = NaiveDateIterator::new(day, day, Duration::days(1)).unwrap();
+        assert_eq!(iter.next(), Some(day));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_zero_step_error() {
+        let start = date(2023, 1, 1);
+        let end = date(2023, 1, 3);
+
+        let result = NaiveDateIterator::new(start, end, Duration::zero());
+        assert!(matches!(result, Err(NaiveDateIterError::ZeroStep)));
+    }
+
+    #[test]
+    fn test_invalid_range_error() {
+        let start = date(2023, 1, 3);
+        let end = date(2023, 1, 1);
+
+        let result = NaiveDateIterator::new(start, end, Duration::days(1));
+        assert!(matches!(
+            result,
+            Err(NaiveDateIterError::InvalidRange { .. })
+        ));
+    }
+
+    #[test]
+    fn test_sub_day_step_error() {
+        let start = date(2023, 1, 1);
+        let end = date(2023, 1, 3);
+
+        for step in [
+            Duration::hours(12),
+            Duration::hours(-36),
+            Duration::nanoseconds(1),
+        ] {
+            let result = NaiveDateIterator::new(start, end, step);
+            assert!(matches!(
+                result,
+                Err(NaiveDateIterError::FractionalDayStep { step: s }) if s == step
+            ));
+        }
+    }
+}
@@ -0,0 +1,167 @@
+use super::month_iter::NaiveDateIterError;
+use super::naive_date_iter::{midnight, validate_date_grid};
+use super::naive_datetime_range_iter::NaiveDatetimeRangeIterator;
+use chrono::{Duration, NaiveDate};
+
+/// An iterator that produces consecutive date ranges
+///
+/// This is the date-only counterpart of [`NaiveDatetimeRangeIterator`]: it
+/// yields `(start, end)` pairs of consecutive dates from a
+/// [`NaiveDateIterator`](crate::iter::NaiveDateIterator) with the same bounds
+/// and step.
+///
+/// # Examples
+///
+/// Forward iteration:
+/// ```
+/// use chrono_kit::iter::NaiveDateRangeIterator;
+/// use chrono::{NaiveDate, Duration};
+///
+/// let start = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+/// let end = NaiveDate::from_ymd_opt(2023, 1, 3).unwrap();
+/// let step = Duration::days(1);
+///
+/// let mut iter = NaiveDateRangeIterator::new(start, end, step).unwrap();
+/// assert_eq!(iter.next(), Some((start, start + step)));
+/// assert_eq!(iter.next(), Some((start + step, end)));
+/// ```
+///
+/// Reverse iteration:
+/// ```
+/// use chrono_kit::iter::NaiveDateRangeIterator;
+/// use chrono::{NaiveDate, Duration};
+///
+/// let start = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+/// let end = NaiveDate::from_ymd_opt(2023, 1, 3).unwrap();
+/// let step = Duration::days(-1);
+///
+/// let mut iter = NaiveDateRangeIterator::new(start, end, step).unwrap();
+/// assert_eq!(iter.next(), Some((end + step, end)));
+/// assert_eq!(iter.next(), Some((start, end + step)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct NaiveDateRangeIterator {
+    range_iter: NaiveDatetimeRangeIterator,
+}
+
+impl NaiveDateRangeIterator {
+    /// Creates a new NaiveDateRangeIterator
+    ///
+    /// # Arguments
+    /// * `start` - The starting date (inclusive)
+    /// * `end` - The ending date (inclusive)
+    /// * `step` - The duration between each step (a non-zero whole number of days)
+    ///
+    /// # Errors
+    /// Returns `NaiveDateIterError` if:
+    /// - `step` is zero
+    /// - `step` is not a whole number of days
+    /// - `start` is after `end`
+    pub fn new(
+        start: NaiveDate,
+        end: NaiveDate,
+        step: Duration,
+    ) -> Result<Self, NaiveDateIterError> {
+        validate_date_grid(start, end, step)?;
+        let range_iter = NaiveDatetimeRangeIterator::new(midnight(start), midnight(end), step)
+            .expect("date grid is validated");
+        Ok(NaiveDateRangeIterator { range_iter })
+    }
+}
+
+impl Iterator for NaiveDateRangeIterator {
+    type Item = (NaiveDate, NaiveDate);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.range_iter
+            .next()
+            .map(|(start, end)| (start.date(), end.date()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range_iter.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for NaiveDateRangeIterator {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.range_iter
+            .next_back()
+            .map(|(start, end)| (start.date(), end.date()))
+    }
+}
+
+impl ExactSizeIterator for NaiveDateRangeIterator {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_ascending_range_iteration() {
+        let start = date(2023, 1, 1);
+        let end = date(2023, 1, 3);
+        let step = Duration::days(1);
+
+        let mut iter = NaiveDateRangeIterator::new(start, end, step).unwrap();
+        assert_eq!(iter.next(), Some((start, start + step)));
+        assert_eq!(iter.next(), Some((start + step, end)));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_descending_range_iteration() {
+        let start = date(2023, 1, 1);
+        let end = date(2023, 1, 3);
+        let step = Duration::days(-1);
+
+        let mut iter = NaiveDateRangeIterator::new(start, end, step).unwrap();
+        assert_eq!(iter.next(), Some((end + step, end)));
+        assert_eq!(iter.next(), Some((start, end + step)));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_non_integer_period() {
+        let start = date(2023, 1, 1);
+        let end = date(2023, 1, 10);
+        let step = Duration::weeks(1);
+
+        let mut iter = NaiveDateRangeIterator::new(start, end, step).unwrap();
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.next(), Some((start, start + step)));
+        assert_eq!(iter.next(), Some((start + step, end)));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_single_date_yields_no_ranges() {
+        let day = date(2023, 1, 1);
+
+        let mut iter = NaiveDateRangeIterator::new(day, day, Duration::days(1)).unwrap();
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_errors() {
+        let start = date(2023, 1, 1);
+        let end = date(2023, 1, 3);
+
+        assert!(matches!(
+            NaiveDateRangeIterator::new(start, end, Duration::zero()),
+            Err(NaiveDateIterError::ZeroStep)
+        ));
+        assert!(matches!(
+            NaiveDateRangeIterator::new(end, start, Duration::days(1)),
+            Err(NaiveDateIterError::InvalidRange { .. })
+        ));
+        assert!(matches!(
+            NaiveDateRangeIterator::new(start, end, Duration::hours(6)),
+            Err(NaiveDateIterError::FractionalDayStep { .. })
+        ));
+    }
+}
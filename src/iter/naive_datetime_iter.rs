@@ -1,4 +1,8 @@
+use super::align::aligned_start;
+use super::step::{ParseStepError, Step};
+use super::util::{duration_to_nanos, nanos_to_duration};
 use chrono::{Duration, NaiveDateTime};
+use std::iter::FusedIterator;
 use thiserror::Error;
 
 /// Errors that can occur when creating a datetime iterator
@@ -13,15 +17,111 @@ pub enum NaiveDatetimeIterError {
         start: NaiveDateTime,
         end: NaiveDateTime,
     },
+    /// Returned when start datetime is not an epoch-aligned multiple of the step
+    #[error("Misaligned start: {start} is not on the step grid")]
+    MisalignedStart { start: NaiveDateTime },
+    /// Returned when end datetime is not an epoch-aligned multiple of the step
+    #[error("Misaligned end: {end} is not on the step grid")]
+    MisalignedEnd { end: NaiveDateTime },
+    /// Returned when a step pattern is empty or contains a non-positive duration
+    #[error("Step pattern must be non-empty and contain only positive durations")]
+    InvalidStepPattern,
+    /// Returned when a fraction or percentile is outside `[0, 1]`
+    #[error("Invalid fraction: {value} must be between 0 and 1")]
+    InvalidFraction { value: f64 },
+    /// Returned when the calendar and duration parts of a step have opposite signs
+    #[error("Step months and duration must have the same sign")]
+    MixedStepSigns,
+    /// Returned when a step string is not a valid ISO 8601 duration
+    #[error(transparent)]
+    InvalidIsoStep(#[from] ParseStepError),
+    /// Returned when a step in seconds is outside the range of `Duration`
+    #[error("Step of {seconds} seconds is out of range")]
+    StepOutOfRange { seconds: i64 },
+    /// Returned when a span is divided into zero steps or zero ranges are grouped
+    #[error("Step count cannot be zero")]
+    ZeroCount,
+    /// Returned when a checkpoint does not describe a valid iterator state
+    #[error("Invalid checkpoint: position is outside the grid")]
+    InvalidCheckpoint,
+    /// Returned when a grid has more points than fit in a `usize`
+    #[error("Too many points: {len} datetimes do not fit in a usize")]
+    TooManyPoints { len: u128 },
+    /// Returned when snapping a start onto a step grid leaves the range of `NaiveDateTime`
+    #[error("Snapping {start} onto the step grid is out of range")]
+    SnapOutOfRange { start: NaiveDateTime },
+    /// Returned when an ISO 8601 interval string is malformed
+    #[error("Invalid ISO 8601 interval {input:?}: {reason}")]
+    ParseError { input: String, reason: &'static str },
 }
 
 /// Iterator that yields datetimes between start and end with given step
 ///
 /// Handles both ascending and descending iteration based on step sign.
+///
+/// The i-th datetime is computed directly as `start + step * i` (or
+/// `end + step * i` for descending steps) rather than by repeatedly adding
+/// `step`, so no error accumulates over long iterations. Calendar steps built
+/// with [`with_step`](Self::with_step) are applied the same way, so a monthly
+/// grid starting on the 31st returns to the 31st whenever the month allows.
+///
+/// Grids with more datetimes than fit in a `usize`, such as a one-nanosecond
+/// step over centuries, are rejected at construction with
+/// [`TooManyPoints`](NaiveDatetimeIterError::TooManyPoints), so the
+/// [`ExactSizeIterator`] length is always exact.
+#[derive(Debug, Clone)]
 pub struct NaiveDatetimeIterator {
     start: NaiveDateTime,
     end: NaiveDateTime,
-    step: Duration,
+    step: Step,
+    /// Total number of datetimes on the grid, including the clamped tail
+    /// unless the far bound is exclusive
+    len: u128,
+    /// Grid positions not yet yielded, as the half-open window `[front, back)`
+    front: u128,
+    back: u128,
+    /// The limit a direction flip walks back to: the lowest position not taken
+    /// from the low end while walking forwards, or the highest not taken from
+    /// the high end while flipped
+    parked: u128,
+    /// Whether the window is walked from `back` downwards after a direction flip
+    flipped: bool,
+    /// Whether the far bound and the clamped tail are left out of the grid
+    exclusive: bool,
+}
+
+/// Returns the number of datetimes a grid yields, including the clamped tail
+fn grid_len(start: NaiveDateTime, end: NaiveDateTime, step: Step) -> u128 {
+    const NANOS_PER_DAY: u128 = 86_400 * 1_000_000_000;
+
+    let span = duration_to_nanos(end - start).unsigned_abs();
+    let step_nanos = duration_to_nanos(step.duration).unsigned_abs();
+    if !step.is_calendar() {
+        return span / step_nanos + 1 + u128::from(!span.is_multiple_of(step_nanos));
+    }
+
+    // Calendar steps vary in length, so binary search for the last point in
+    // range. Every month is at least 28 days long, which bounds the search.
+    let (origin, terminal) = if step.is_positive() {
+        (start, end)
+    } else {
+        (end, start)
+    };
+    let in_range = |index| {
+        step.apply(origin, index)
+            .is_some_and(|dt| start <= dt && dt <= end)
+    };
+    let min_step = step.months.unsigned_abs() as u128 * 28 * NANOS_PER_DAY + step_nanos;
+    let (mut last, mut past) = (0, span / min_step + 1);
+    while past - last > 1 {
+        let mid = last + (past - last) / 2;
+        if in_range(mid) {
+            last = mid;
+        } else {
+            past = mid;
+        }
+    }
+    last + 1 + u128::from(step.apply(origin, last) != Some(terminal))
 }
 
 impl NaiveDatetimeIterator {
@@ -36,63 +136,748 @@ impl NaiveDatetimeIterator {
     /// Returns `DatetimeIterError` if:
     /// - `step` is zero
     /// - `start` is after `end` for positive step
+    /// - the grid has more datetimes than fit in a `usize`
     pub fn new(
         start: NaiveDateTime,
         end: NaiveDateTime,
         step: Duration,
+    ) -> Result<Self, NaiveDatetimeIterError> {
+        Self::with_step(start, end, Step::from(step))
+    }
+
+    /// Creates a new DatetimeIterator with a step that may include calendar months
+    ///
+    /// # Arguments
+    /// * `start` - The starting datetime (inclusive)
+    /// * `end` - The ending datetime (inclusive)
+    /// * `step` - The step between each datetime (must be non-zero)
+    ///
+    /// # Errors
+    /// Returns `DatetimeIterError` if:
+    /// - `step` is zero
+    /// - the months and duration of `step` have opposite signs
+    /// - `start` is after `end`
+    /// - the grid has more datetimes than fit in a `usize`
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono_kit::iter::{parse_iso_duration, NaiveDatetimeIterator};
+    /// use chrono::NaiveDateTime;
+    ///
+    /// let start = NaiveDateTime::parse_from_str("2023-01-31 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let end = NaiveDateTime::parse_from_str("2023-03-31 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let step = parse_iso_duration("P1M").unwrap();
+    ///
+    /// let dates: Vec<_> = NaiveDatetimeIterator::with_step(start, end, step)
+    ///     .unwrap()
+    ///     .map(|dt| dt.date().to_string())
+    ///     .collect();
+    /// assert_eq!(dates, vec!["2023-01-31", "2023-02-28", "2023-03-31"]);
+    /// ```
+    pub fn with_step(
+        start: NaiveDateTime,
+        end: NaiveDateTime,
+        step: Step,
     ) -> Result<Self, NaiveDatetimeIterError> {
         if step.is_zero() {
             return Err(NaiveDatetimeIterError::ZeroStep);
         }
+        if step.has_mixed_signs() {
+            return Err(NaiveDatetimeIterError::MixedStepSigns);
+        }
+        if start > end {
+            return Err(NaiveDatetimeIterError::InvalidRange { start, end });
+        }
+        let len = grid_len(start, end, step);
+        if usize::try_from(len).is_err() {
+            return Err(NaiveDatetimeIterError::TooManyPoints { len });
+        }
+        Ok(NaiveDatetimeIterator {
+            start,
+            end,
+            step,
+            len,
+            front: 0,
+            back: len,
+            parked: 0,
+            flipped: false,
+            exclusive: false,
+        })
+    }
+
+    /// Creates a new DatetimeIterator dividing `[start, end]` into `n` equal steps
+    ///
+    /// Yields the `n + 1` boundaries from `start` to exactly `end`. The step
+    /// is the span divided by `n`, rounded down to whole nanoseconds, and the
+    /// remainder is absorbed into the last step, making it at most `n - 1`
+    /// nanoseconds longer than the others.
+    ///
+    /// # Errors
+    /// Returns `DatetimeIterError` if:
+    /// - `n` is zero
+    /// - `start` is after `end`
+    /// - the span is shorter than `n` nanoseconds, so the step would be zero
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono_kit::iter::NaiveDatetimeIterator;
+    /// use chrono::{NaiveDateTime, Duration};
+    ///
+    /// let start = NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let end = NaiveDateTime::parse_from_str("2023-01-01 10:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    ///
+    /// let points: Vec<_> = NaiveDatetimeIterator::with_count(start, end, 4).unwrap().collect();
+    /// assert_eq!(points.len(), 5);
+    /// assert_eq!(points[1], start + Duration::minutes(150));
+    /// assert_eq!(points[4], end);
+    /// ```
+    pub fn with_count(
+        start: NaiveDateTime,
+        end: NaiveDateTime,
+        n: usize,
+    ) -> Result<Self, NaiveDatetimeIterError> {
+        if n == 0 {
+            return Err(NaiveDatetimeIterError::ZeroCount);
+        }
+        if start > end {
+            return Err(NaiveDatetimeIterError::InvalidRange { start, end });
+        }
+        let step = nanos_to_duration(duration_to_nanos(end - start) / n as i128)
+            .expect("a fraction of a valid span is a valid duration");
+
+        // Flooring the step can leave room for one more point before `end`,
+        // so cap the grid to make `end` the (n + 1)-th point
+        Ok(Self::new(start, end, step)?
+            .with_len(n as u128 + 1)
+            .expect("n + 1 points fit on the floored grid"))
+    }
+
+    /// Returns `n` evenly spaced datetimes from `start` to exactly `end`
+    ///
+    /// Like numpy's `linspace`, the i-th datetime is computed directly as
+    /// `start + (end - start) * i / (n - 1)`, rounded down to whole
+    /// nanoseconds, so no rounding error accumulates and consecutive
+    /// datetimes are at most one nanosecond further apart than others. A
+    /// single datetime is just `start`.
+    ///
+    /// Compare [`with_count`](Self::with_count), which takes the number of
+    /// steps rather than points and uses one fixed step.
+    ///
+    /// # Errors
+    /// Returns `DatetimeIterError` if:
+    /// - `n` is zero
+    /// - `start` is after `end`
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono_kit::iter::NaiveDatetimeIterator;
+    /// use chrono::{NaiveDateTime, Duration};
+    ///
+    /// let start = NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let end = NaiveDateTime::parse_from_str("2023-01-01 01:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    ///
+    /// let points: Vec<_> = NaiveDatetimeIterator::linspace(start, end, 5).unwrap().collect();
+    /// assert_eq!(points.len(), 5);
+    /// assert_eq!(points[1], start + Duration::minutes(15));
+    /// assert_eq!(points[4], end);
+    /// ```
+    pub fn linspace(
+        start: NaiveDateTime,
+        end: NaiveDateTime,
+        n: usize,
+    ) -> Result<
+        impl DoubleEndedIterator<Item = NaiveDateTime> + ExactSizeIterator + Clone,
+        NaiveDatetimeIterError,
+    > {
+        if n == 0 {
+            return Err(NaiveDatetimeIterError::ZeroCount);
+        }
         if start > end {
             return Err(NaiveDatetimeIterError::InvalidRange { start, end });
         }
-        Ok(NaiveDatetimeIterator { start, end, step })
+
+        // Split the span so that `span * i / gaps` never overflows
+        let span = duration_to_nanos(end - start).unsigned_abs();
+        let gaps = (n as u128 - 1).max(1);
+        let (quotient, remainder) = (span / gaps, span % gaps);
+        Ok((0..n).map(move |i| {
+            let i = i as u128;
+            let offset = quotient * i + remainder * i / gaps;
+            start + nanos_to_duration(offset as i128).expect("offset lies within the span")
+        }))
+    }
+
+    /// Creates a new DatetimeIterator that stops short of the far bound
+    ///
+    /// For ascending steps datetimes are yielded only while strictly before
+    /// `end`, and for descending steps only while strictly after `start`. The
+    /// clamped tail is never yielded, so the iterator is empty when `start`
+    /// equals `end`.
+    ///
+    /// Ranges from [`NaiveDatetimeRangeIterator`](crate::iter::NaiveDatetimeRangeIterator)
+    /// are built on the inclusive grid and always finish exactly at the far
+    /// bound, with a shorter final range if the span is not a whole number of
+    /// steps. Pairing up the points of an exclusive iterator instead stops at
+    /// the last whole step before the bound.
+    ///
+    /// # Arguments
+    /// * `start` - The starting datetime (inclusive for ascending steps)
+    /// * `end` - The ending datetime (inclusive for descending steps)
+    /// * `step` - The duration between each step (must be non-zero)
+    ///
+    /// # Errors
+    /// Returns `DatetimeIterError` if:
+    /// - `step` is zero
+    /// - `start` is after `end`
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono_kit::iter::NaiveDatetimeIterator;
+    /// use chrono::{NaiveDateTime, Duration};
+    ///
+    /// let start = NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let end = NaiveDateTime::parse_from_str("2023-01-02 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    ///
+    /// let hours: Vec<_> = NaiveDatetimeIterator::new_exclusive(start, end, Duration::hours(1))
+    ///     .unwrap()
+    ///     .collect();
+    /// assert_eq!(hours.len(), 24);
+    /// assert_eq!(hours.last(), Some(&(end - Duration::hours(1))));
+    /// ```
+    pub fn new_exclusive(
+        start: NaiveDateTime,
+        end: NaiveDateTime,
+        step: Duration,
+    ) -> Result<Self, NaiveDatetimeIterError> {
+        Ok(Self::new(start, end, step)?.exclude_far_bound())
+    }
+
+    /// Drops the far bound and the clamped tail from a freshly built grid
+    pub(crate) fn exclude_far_bound(mut self) -> Self {
+        self.exclusive = true;
+        self.len -= 1;
+        self.back = self.len;
+        self
+    }
+
+    /// Creates a new DatetimeIterator whose endpoints must lie on the step grid
+    ///
+    /// The grid is anchored at the Unix epoch, so with a one-hour step both
+    /// `start` and `end` must fall on the top of an hour.
+    ///
+    /// # Errors
+    /// Returns `DatetimeIterError` if:
+    /// - `step` is zero
+    /// - `start` is after `end` for positive step
+    /// - `start` or `end` is not an epoch-aligned multiple of `step`
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono_kit::iter::{NaiveDatetimeIterator, NaiveDatetimeIterError};
+    /// use chrono::{NaiveDateTime, Duration};
+    ///
+    /// let start = NaiveDateTime::parse_from_str("2023-01-01 00:00:37", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let end = NaiveDateTime::parse_from_str("2023-01-02 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    ///
+    /// let result = NaiveDatetimeIterator::new_strict_aligned(start, end, Duration::hours(1));
+    /// assert!(matches!(result, Err(NaiveDatetimeIterError::MisalignedStart { .. })));
+    /// ```
+    pub fn new_strict_aligned(
+        start: NaiveDateTime,
+        end: NaiveDateTime,
+        step: Duration,
+    ) -> Result<Self, NaiveDatetimeIterError> {
+        let iter = Self::new(start, end, step)?;
+        if aligned_start(start, step, Duration::zero()) != start {
+            return Err(NaiveDatetimeIterError::MisalignedStart { start });
+        }
+        if aligned_start(end, step, Duration::zero()) != end {
+            return Err(NaiveDatetimeIterError::MisalignedEnd { end });
+        }
+        Ok(iter)
+    }
+
+    /// Reverses the direction of iteration from the current position
+    ///
+    /// The iterator behaves like a cursor sitting just after the last yielded
+    /// datetime. Flipping turns the cursor around, so the following `next`
+    /// calls walk back over the already-yielded datetimes, starting with the
+    /// most recent one, until the first grid point is reached. Flipping again
+    /// resumes in the original direction, yielding the datetimes after the
+    /// cursor through to the far bound. Flipping an unstarted iterator leaves
+    /// nothing to walk back over, so it yields nothing. Datetimes taken with
+    /// [`next_back`](DoubleEndedIterator::next_back) are never revisited, however
+    /// often the direction is flipped.
+    ///
+    /// The grid itself never changes: the same datetimes are visited,
+    /// including the clamped tail, only in the opposite order.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono_kit::iter::NaiveDatetimeIterator;
+    /// use chrono::{NaiveDateTime, Duration};
+    ///
+    /// let start = NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let end = NaiveDateTime::parse_from_str("2023-01-01 05:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let hour = Duration::hours(1);
+    ///
+    /// let mut iter = NaiveDatetimeIterator::new(start, end, hour).unwrap();
+    /// iter.nth(2);
+    /// iter.flip_direction();
+    /// assert_eq!(iter.collect::<Vec<_>>(), vec![start + hour * 2, start + hour, start]);
+    /// ```
+    pub fn flip_direction(&mut self) {
+        if self.flipped {
+            (self.front, self.back, self.parked) = (self.back, self.parked, self.front);
+        } else {
+            (self.front, self.back, self.parked) = (self.parked, self.front, self.back);
+        }
+        self.flipped = !self.flipped;
+    }
+
+    /// Returns an iterator over the remaining datetimes in the opposite order
+    ///
+    /// Unlike [`flip_direction`](Self::flip_direction), which turns around at
+    /// the cursor, this keeps exactly the datetimes not yet yielded from
+    /// either end and walks them backwards, like [`Iterator::rev`] but
+    /// without changing the type. The grid itself is kept rather than rebuilt
+    /// with a negated step, so a clamped tail stays where it was instead of
+    /// moving to the other bound.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono_kit::iter::NaiveDatetimeIterator;
+    /// use chrono::{NaiveDateTime, Duration};
+    ///
+    /// let start = NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let end = NaiveDateTime::parse_from_str("2023-01-01 05:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    ///
+    /// let iter = NaiveDatetimeIterator::new(start, end, Duration::hours(2)).unwrap();
+    /// let points: Vec<_> = iter.reversed().map(|dt| dt.to_string()).collect();
+    /// assert_eq!(points, vec!["2023-01-01 05:00:00", "2023-01-01 04:00:00", "2023-01-01 02:00:00", "2023-01-01 00:00:00"]);
+    /// ```
+    pub fn reversed(mut self) -> Self {
+        // Nothing outside the remaining window is walked back over after a later flip
+        self.parked = if self.flipped { self.front } else { self.back };
+        self.flipped = !self.flipped;
+        self
+    }
+
+    /// Returns `true` if both iterators were configured to produce the same grid
+    ///
+    /// Compares the bounds given at construction and the step, including its
+    /// sign, so equivalent steps such as `Duration::hours(1)` and
+    /// `Duration::minutes(60)` compare equal. The number of grid points is
+    /// compared too, so a grid cut short by [`new_exclusive`](Self::new_exclusive)
+    /// or [`with_count`](Self::with_count) differs from the full one. So is the
+    /// direction of iteration: an iterator turned around by
+    /// [`flip_direction`](Self::flip_direction) or [`reversed`](Self::reversed)
+    /// differs from one walking the grid the original way. How far either
+    /// iterator has advanced is ignored.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono_kit::iter::NaiveDatetimeIterator;
+    /// use chrono::{NaiveDateTime, Duration};
+    ///
+    /// let start = NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let end = NaiveDateTime::parse_from_str("2023-01-02 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    ///
+    /// let hourly = NaiveDatetimeIterator::new(start, end, Duration::hours(1)).unwrap();
+    /// let minutely = NaiveDatetimeIterator::new(start, end, Duration::minutes(60)).unwrap();
+    /// assert!(hourly.grid_eq(&minutely));
+    /// ```
+    pub fn grid_eq(&self, other: &Self) -> bool {
+        self.start == other.start
+            && self.end == other.end
+            && self.step == other.step
+            && self.len == other.len
+            && self.flipped == other.flipped
+    }
+
+    /// Returns `true` if `dt` is one of the datetimes left to yield
+    ///
+    /// For steps without calendar months this is O(1): `dt` must lie on the
+    /// step grid counted from the near bound, or be the clamped tail. Calendar
+    /// steps vary in length, so their grid is binary searched instead. Either
+    /// way the answer agrees with draining the iterator and searching for `dt`.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono_kit::iter::NaiveDatetimeIterator;
+    /// use chrono::{NaiveDateTime, Duration};
+    ///
+    /// let start = NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let end = NaiveDateTime::parse_from_str("2023-01-01 05:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    ///
+    /// let iter = NaiveDatetimeIterator::new(start, end, Duration::hours(2)).unwrap();
+    /// assert!(iter.contains(start + Duration::hours(4)));
+    /// assert!(iter.contains(end));
+    /// assert!(!iter.contains(start + Duration::hours(1)));
+    /// ```
+    pub fn contains(&self, dt: NaiveDateTime) -> bool {
+        if dt < self.start || dt > self.end {
+            return false;
+        }
+        let asc = self.step.is_positive();
+        let terminal = if asc { self.end } else { self.start };
+        let on_grid = if self.exclusive {
+            self.len
+        } else {
+            self.len - 1
+        };
+
+        let position = if !self.exclusive && dt == terminal {
+            Some(self.len - 1)
+        } else if self.step.is_calendar() {
+            let (mut lo, mut hi) = (0, on_grid);
+            while lo < hi {
+                let mid = lo + (hi - lo) / 2;
+                let point = self.point_at(mid);
+                if (asc && point < dt) || (!asc && point > dt) {
+                    lo = mid + 1;
+                } else {
+                    hi = mid;
+                }
+            }
+            (lo < on_grid && self.point_at(lo) == dt).then_some(lo)
+        } else {
+            let origin = if asc { self.start } else { self.end };
+            let offset = duration_to_nanos(dt - origin).unsigned_abs();
+            let step = duration_to_nanos(self.step.duration).unsigned_abs();
+            (offset.is_multiple_of(step) && offset / step < on_grid).then(|| offset / step)
+        };
+        position.is_some_and(|position| self.front <= position && position < self.back)
+    }
+
+    /// Splits the remaining datetimes into consecutive iterators at each cut
+    ///
+    /// Each cut starts a new iterator at the first remaining datetime at or
+    /// past it in the direction of iteration, so a cut between grid points
+    /// splits there and a cut on a grid point starts the next iterator with
+    /// it. The pieces share this iterator's grid, including the clamped tail,
+    /// and are returned in iteration order, so chaining them yields exactly
+    /// the remaining datetimes. Cuts outside the remaining datetimes and
+    /// repeated cuts are ignored; there are never empty pieces.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono_kit::iter::NaiveDatetimeIterator;
+    /// use chrono::{NaiveDateTime, Duration};
+    ///
+    /// let start = NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let end = NaiveDateTime::parse_from_str("2023-01-01 05:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let hour = Duration::hours(1);
+    ///
+    /// let iter = NaiveDatetimeIterator::new(start, end, hour).unwrap();
+    /// let pieces = iter.split_at_many(&[start + Duration::minutes(90)]);
+    /// let lens: Vec<_> = pieces.into_iter().map(|piece| piece.count()).collect();
+    /// assert_eq!(lens, vec![2, 4]);
+    /// ```
+    pub fn split_at_many(self, cuts: &[NaiveDateTime]) -> Vec<NaiveDatetimeIterator> {
+        let asc = self.step.is_positive();
+        // Grid positions whose datetimes come before `cut` in grid order
+        let boundary = |cut: NaiveDateTime| {
+            let (mut lo, mut hi) = (self.front, self.back);
+            while lo < hi {
+                let mid = lo + (hi - lo) / 2;
+                let point = self.point_at(mid);
+                if (asc && point < cut) || (!asc && point > cut) {
+                    lo = mid + 1;
+                } else {
+                    hi = mid;
+                }
+            }
+            lo
+        };
+
+        let mut bounds: Vec<_> = cuts
+            .iter()
+            .map(|&cut| boundary(cut))
+            .filter(|&position| self.front < position && position < self.back)
+            .collect();
+        bounds.sort_unstable();
+        bounds.dedup();
+        bounds.insert(0, self.front);
+        bounds.push(self.back);
+
+        let mut pieces: Vec<_> = bounds
+            .windows(2)
+            .map(|window| NaiveDatetimeIterator {
+                front: window[0],
+                back: window[1],
+                ..self
+            })
+            .collect();
+        if self.flipped {
+            pieces.reverse();
+        }
+        pieces
+    }
+
+    /// Returns the `(start, end)` bounds given at construction
+    pub(crate) fn original_bounds(&self) -> (NaiveDateTime, NaiveDateTime) {
+        (self.start, self.end)
+    }
+
+    /// Returns the exact duration part of the step given at construction
+    pub(crate) fn step(&self) -> Duration {
+        self.step.duration
+    }
+
+    /// Returns the full step given at construction, including calendar months
+    pub(crate) fn full_step(&self) -> Step {
+        self.step
+    }
+
+    /// Returns `true` if the far bound is left out of the grid
+    pub(crate) fn is_exclusive(&self) -> bool {
+        self.exclusive
+    }
+
+    /// Returns the total number of datetimes on the grid, consumed or not
+    pub(crate) fn total_len(&self) -> u128 {
+        self.len
     }
 
-    fn next_asc(&mut self) -> Option<NaiveDateTime> {
-        if self.start > self.end {
+    /// Truncates a freshly built grid to its first `len` positions
+    ///
+    /// The last remaining position becomes the clamped tail. Returns `None`
+    /// unless `1 <= len <= self.len`.
+    pub(crate) fn with_len(mut self, len: u128) -> Option<Self> {
+        if len == 0 || len > self.len {
             return None;
         }
+        self.len = len;
+        self.back = len;
+        Some(self)
+    }
+
+    /// Returns the window of positions not yet yielded, the limit a flip walks
+    /// back to, and whether the window is walked flipped
+    pub(crate) fn window(&self) -> (u128, u128, u128, bool) {
+        (self.front, self.back, self.parked, self.flipped)
+    }
 
-        let result = self.start;
-        self.start = if self.start < self.end && self.start + self.step > self.end {
-            self.end
+    /// Replaces the window of positions not yet yielded and the flip limit
+    ///
+    /// Returns `None` unless `parked <= front <= back <= len`, or
+    /// `front <= back <= parked <= len` when `flipped`.
+    pub(crate) fn with_window(
+        mut self,
+        front: u128,
+        back: u128,
+        parked: u128,
+        flipped: bool,
+    ) -> Option<Self> {
+        let ordered = if flipped {
+            front <= back && back <= parked && parked <= self.len
         } else {
-            self.start + self.step
+            parked <= front && front <= back && back <= self.len
         };
+        if !ordered {
+            return None;
+        }
+        self.front = front;
+        self.back = back;
+        self.parked = parked;
+        self.flipped = flipped;
+        Some(self)
+    }
+
+    /// Advances the iterator, returning the grid position of the yielded datetime
+    pub(crate) fn next_position(&mut self) -> Option<u128> {
+        if self.front >= self.back {
+            return None;
+        }
+
+        if self.flipped {
+            self.back -= 1;
+            Some(self.back)
+        } else {
+            self.front += 1;
+            Some(self.front - 1)
+        }
+    }
 
-        Some(result)
+    /// Advances the iterator from the back, returning the grid position of the yielded datetime
+    pub(crate) fn next_back_position(&mut self) -> Option<u128> {
+        if self.front >= self.back {
+            return None;
+        }
+
+        if self.flipped {
+            self.front += 1;
+            Some(self.front - 1)
+        } else {
+            self.back -= 1;
+            Some(self.back)
+        }
     }
 
-    fn next_desc(&mut self) -> Option<NaiveDateTime> {
-        if self.end < self.start {
+    /// Returns the grid position `offset` datetimes ahead, without advancing
+    pub(crate) fn position_ahead(&self, offset: u128) -> Option<u128> {
+        if offset >= self.remaining_len() {
             return None;
         }
 
-        let result = self.end;
-        self.end = if self.end > self.start && self.end + self.step < self.start {
-            self.start
+        if self.flipped {
+            Some(self.back - 1 - offset)
+        } else {
+            Some(self.front + offset)
+        }
+    }
+
+    /// Returns the number of datetimes left to yield, computed without iterating
+    ///
+    /// Counts every remaining point on the step grid plus the clamped tail
+    /// element when the span is not a whole multiple of the step. The
+    /// constructors keep the grid within `usize`, so the count always fits.
+    pub(crate) fn remaining(&self) -> usize {
+        self.remaining_len() as usize
+    }
+
+    /// Returns the exact number of datetimes left to yield
+    pub(crate) fn remaining_len(&self) -> u128 {
+        self.back - self.front
+    }
+
+    /// Returns the datetime at position `index` of the grid
+    ///
+    /// Unless the far bound is exclusive, the last position is always the
+    /// clamped tail: `end` for ascending steps and `start` for descending ones.
+    /// `index` must be less than `len`.
+    pub(crate) fn point_at(&self, index: u128) -> NaiveDateTime {
+        let (origin, terminal) = if self.step.is_positive() {
+            (self.start, self.end)
         } else {
-            self.end + self.step
+            (self.end, self.start)
         };
+        if !self.exclusive && index + 1 >= self.len {
+            return terminal;
+        }
 
-        Some(result)
+        // Every non-terminal point lies within the bounds, so it always fits
+        self.step.apply(origin, index).unwrap()
     }
 }
 
+/// Creates a pair of iterators walking outward from `center`
+///
+/// The first iterator walks forward over `[center, center + span]` and the
+/// second walks backward over `[center - span, center]`. Both start at `center`.
+/// The signs of `step` and `span` are ignored, and the far bounds are clamped
+/// to `NaiveDateTime::MIN` and `NaiveDateTime::MAX` instead of overflowing.
+///
+/// # Errors
+/// Returns `NaiveDatetimeIterError` if:
+/// - `step` is zero
+/// - either grid has more datetimes than fit in a `usize`
+///
+/// # Examples
+/// ```
+/// use chrono_kit::iter::from_center;
+/// use chrono::{NaiveDateTime, Duration};
+///
+/// let center = NaiveDateTime::parse_from_str("2023-01-02 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+/// let (mut forward, mut backward) = from_center(center, Duration::hours(1), Duration::days(1)).unwrap();
+/// assert_eq!(forward.next(), Some(center));
+/// assert_eq!(backward.next(), Some(center));
+/// assert_eq!(forward.next(), Some(center + Duration::hours(1)));
+/// assert_eq!(backward.next(), Some(center - Duration::hours(1)));
+/// ```
+pub fn from_center(
+    center: NaiveDateTime,
+    step: Duration,
+    span: Duration,
+) -> Result<(NaiveDatetimeIterator, NaiveDatetimeIterator), NaiveDatetimeIterError> {
+    let step = step.abs();
+    let span = span.abs();
+    let last = center
+        .checked_add_signed(span)
+        .unwrap_or(NaiveDateTime::MAX);
+    let first = center
+        .checked_sub_signed(span)
+        .unwrap_or(NaiveDateTime::MIN);
+    let forward = NaiveDatetimeIterator::new(center, last, step)?;
+    let backward = NaiveDatetimeIterator::new(first, center, -step)?;
+    Ok((forward, backward))
+}
+
+/// Returns the total number of datetimes left across several iterators
+///
+/// Each count is computed arithmetically, so the iterators are not advanced.
+///
+/// # Examples
+/// ```
+/// use chrono_kit::iter::{total_count, NaiveDatetimeIterator};
+/// use chrono::{NaiveDateTime, Duration};
+///
+/// let start = NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+/// let end = NaiveDateTime::parse_from_str("2023-01-02 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+///
+/// let hourly = NaiveDatetimeIterator::new(start, end, Duration::hours(1)).unwrap();
+/// let daily = NaiveDatetimeIterator::new(start, end, Duration::days(1)).unwrap();
+/// assert_eq!(total_count(&[hourly, daily]), 25 + 2);
+/// ```
+pub fn total_count(iters: &[NaiveDatetimeIterator]) -> usize {
+    iters
+        .iter()
+        .fold(0usize, |total, iter| total.saturating_add(iter.remaining()))
+}
+
 impl Iterator for NaiveDatetimeIterator {
     type Item = NaiveDateTime;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.step > Duration::zero() {
-            self.next_asc()
+        let position = self.next_position()?;
+        Some(self.point_at(position))
+    }
+
+    /// Skips `n` datetimes in constant time, exhausting the iterator if fewer remain
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let Some(position) = self.position_ahead(n as u128) else {
+            self.front = self.back;
+            return None;
+        };
+
+        if self.flipped {
+            self.back = position;
         } else {
-            self.next_desc()
+            self.front = position + 1;
         }
+        Some(self.point_at(position))
+    }
+
+    /// Returns the exact number of remaining datetimes, computed without iterating
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.remaining();
+        (len, Some(len))
+    }
+
+    /// Returns the number of remaining datetimes, computed without iterating
+    fn count(self) -> usize {
+        self.remaining()
+    }
+
+    /// Returns the final datetime in constant time, without iterating
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
     }
 }
 
+impl DoubleEndedIterator for NaiveDatetimeIterator {
+    /// Yields from the far end: the high end for ascending steps and the low end for descending ones
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let position = self.next_back_position()?;
+        Some(self.point_at(position))
+    }
+}
+
+impl ExactSizeIterator for NaiveDatetimeIterator {}
+
+impl FusedIterator for NaiveDatetimeIterator {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -168,4 +953,987 @@ mod tests {
         assert_eq!(iter.next(), Some(start + step * 2));
         assert_eq!(iter.next(), Some(end));
     }
+
+    #[test]
+    fn test_new_strict_aligned() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let end =
+            NaiveDateTime::parse_from_str("2023-01-01 06:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let step = Duration::hours(1);
+
+        let iter = NaiveDatetimeIterator::new_strict_aligned(start, end, step).unwrap();
+        assert_eq!(iter.count(), 7);
+        assert!(NaiveDatetimeIterator::new_strict_aligned(start, end, -step).is_ok());
+    }
+
+    #[test]
+    fn test_new_strict_aligned_misaligned_start() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-01 00:00:37", "%Y-%m-%d %H:%M:%S").unwrap();
+        let end =
+            NaiveDateTime::parse_from_str("2023-01-01 06:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+
+        let result = NaiveDatetimeIterator::new_strict_aligned(start, end, Duration::hours(1));
+        assert!(matches!(
+            result,
+            Err(NaiveDatetimeIterError::MisalignedStart { start: s }) if s == start
+        ));
+    }
+
+    #[test]
+    fn test_new_strict_aligned_misaligned_end() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let end =
+            NaiveDateTime::parse_from_str("2023-01-01 06:30:00", "%Y-%m-%d %H:%M:%S").unwrap();
+
+        let result = NaiveDatetimeIterator::new_strict_aligned(start, end, Duration::hours(1));
+        assert!(matches!(
+            result,
+            Err(NaiveDatetimeIterError::MisalignedEnd { end: e }) if e == end
+        ));
+    }
+
+    #[test]
+    fn test_remaining_matches_iteration() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let end =
+            NaiveDateTime::parse_from_str("2023-01-03 12:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+
+        for step in [
+            Duration::days(1),
+            Duration::days(-1),
+            Duration::hours(7),
+            Duration::hours(-7),
+            Duration::days(5),
+        ] {
+            let mut iter = NaiveDatetimeIterator::new(start, end, step).unwrap();
+            let mut expected = NaiveDatetimeIterator::new(start, end, step)
+                .unwrap()
+                .count();
+            loop {
+                assert_eq!(iter.remaining(), expected);
+                if iter.next().is_none() {
+                    break;
+                }
+                expected -= 1;
+            }
+        }
+    }
+
+    #[test]
+    fn test_len_matches_items_produced() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let end =
+            NaiveDateTime::parse_from_str("2023-01-03 12:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+
+        for step in [
+            Duration::days(1),
+            Duration::days(-1),
+            Duration::hours(7),
+            Duration::hours(-7),
+            Duration::days(5),
+            Duration::days(-5),
+        ] {
+            for skip in 0..4 {
+                let mut iter = NaiveDatetimeIterator::new(start, end, step).unwrap();
+                for _ in 0..skip {
+                    iter.next();
+                }
+                let len = iter.len();
+                assert_eq!(iter.size_hint(), (len, Some(len)));
+                assert_eq!(iter.collect::<Vec<_>>().len(), len);
+            }
+        }
+    }
+
+    #[test]
+    fn test_next_back_interleaved() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let end =
+            NaiveDateTime::parse_from_str("2023-01-01 10:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+
+        for step in [Duration::hours(3), Duration::hours(-3), Duration::hours(1)] {
+            let expected: Vec<_> = NaiveDatetimeIterator::new(start, end, step)
+                .unwrap()
+                .collect();
+
+            let mut iter = NaiveDatetimeIterator::new(start, end, step).unwrap();
+            let (mut front, mut back) = (Vec::new(), Vec::new());
+            for i in 0.. {
+                let item = if i % 3 == 0 {
+                    iter.next_back().map(|dt| back.push(dt))
+                } else {
+                    iter.next().map(|dt| front.push(dt))
+                };
+                if item.is_none() {
+                    break;
+                }
+            }
+            assert_eq!(iter.next(), None);
+            assert_eq!(iter.next_back(), None);
+
+            front.extend(back.into_iter().rev());
+            assert_eq!(front, expected);
+        }
+    }
+
+    #[test]
+    fn test_rev_after_flip() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let end =
+            NaiveDateTime::parse_from_str("2023-01-01 03:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let hour = Duration::hours(1);
+
+        let mut iter = NaiveDatetimeIterator::new(start, end, hour).unwrap();
+        iter.nth(2);
+        iter.flip_direction();
+        assert_eq!(
+            iter.rev().collect::<Vec<_>>(),
+            vec![start, start + hour, start + hour * 2]
+        );
+    }
+
+    #[test]
+    fn test_new_exclusive() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let end =
+            NaiveDateTime::parse_from_str("2023-01-01 06:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let step = Duration::hours(2);
+
+        let points: Vec<_> = NaiveDatetimeIterator::new_exclusive(start, end, step)
+            .unwrap()
+            .collect();
+        assert_eq!(points, vec![start, start + step, start + step * 2]);
+
+        let points: Vec<_> = NaiveDatetimeIterator::new_exclusive(start, end, -step)
+            .unwrap()
+            .collect();
+        assert_eq!(points, vec![end, end - step, end - step * 2]);
+    }
+
+    #[test]
+    fn test_new_exclusive_non_integer_span() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let end =
+            NaiveDateTime::parse_from_str("2023-01-01 05:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let step = Duration::hours(2);
+
+        let iter = NaiveDatetimeIterator::new_exclusive(start, end, step).unwrap();
+        assert_eq!(iter.len(), 3);
+        assert_eq!(
+            iter.collect::<Vec<_>>(),
+            vec![start, start + step, start + step * 2]
+        );
+
+        let mut iter = NaiveDatetimeIterator::new_exclusive(start, end, -step).unwrap();
+        assert_eq!(iter.next_back(), Some(end - step * 2));
+        assert_eq!(iter.collect::<Vec<_>>(), vec![end, end - step]);
+    }
+
+    #[test]
+    fn test_new_exclusive_empty() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+
+        let mut iter =
+            NaiveDatetimeIterator::new_exclusive(start, start, Duration::hours(1)).unwrap();
+        assert_eq!(iter.len(), 0);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_split_at_many_concatenates_to_original() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let end =
+            NaiveDateTime::parse_from_str("2023-01-01 10:30:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let cuts = [
+            start + Duration::hours(6),
+            start - Duration::hours(1),
+            start + Duration::minutes(150),
+            start + Duration::hours(6),
+            end + Duration::hours(1),
+        ];
+
+        for step in [Duration::hours(2), Duration::hours(-2)] {
+            let expected: Vec<_> = NaiveDatetimeIterator::new(start, end, step)
+                .unwrap()
+                .collect();
+            let pieces = NaiveDatetimeIterator::new(start, end, step)
+                .unwrap()
+                .split_at_many(&cuts);
+            assert_eq!(pieces.len(), 3);
+
+            let pieces: Vec<Vec<_>> = pieces.into_iter().map(Iterator::collect).collect();
+            assert!(pieces.iter().all(|piece| !piece.is_empty()));
+            assert_eq!(pieces.concat(), expected);
+        }
+    }
+
+    #[test]
+    fn test_split_at_many_on_grid_point() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let end =
+            NaiveDateTime::parse_from_str("2023-01-01 04:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let hour = Duration::hours(1);
+
+        let mut iter = NaiveDatetimeIterator::new(start, end, hour).unwrap();
+        iter.next();
+        let pieces: Vec<Vec<_>> = iter
+            .split_at_many(&[start, start + hour * 3])
+            .into_iter()
+            .map(Iterator::collect)
+            .collect();
+        assert_eq!(
+            pieces,
+            vec![
+                vec![start + hour, start + hour * 2],
+                vec![start + hour * 3, end]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_total_count() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let end =
+            NaiveDateTime::parse_from_str("2023-01-02 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+
+        let mut partial = NaiveDatetimeIterator::new(start, end, Duration::hours(-1)).unwrap();
+        partial.nth(4);
+        let iters = [
+            NaiveDatetimeIterator::new(start, end, Duration::hours(1)).unwrap(),
+            NaiveDatetimeIterator::new(start, end, Duration::hours(5)).unwrap(),
+            partial,
+        ];
+        assert_eq!(total_count(&iters), 25 + 6 + 20);
+        assert_eq!(total_count(&[]), 0);
+    }
+
+    #[test]
+    fn test_no_drift_over_million_steps() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let step = Duration::nanoseconds(333_333_333);
+        let n = 1_000_000;
+        let end = start + step * n;
+
+        let mut iter = NaiveDatetimeIterator::new(start, end, step).unwrap();
+        assert_eq!(iter.nth(n as usize - 1), Some(start + step * (n - 1)));
+        assert_eq!(iter.next(), Some(start + step * n));
+        assert_eq!(iter.next(), None);
+
+        let mut iter = NaiveDatetimeIterator::new(start, end, -step).unwrap();
+        assert_eq!(iter.nth(n as usize - 1), Some(end - step * (n - 1)));
+        assert_eq!(iter.next(), Some(start));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_calendar_step() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-31 12:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let end =
+            NaiveDateTime::parse_from_str("2023-05-15 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+
+        let iter = NaiveDatetimeIterator::with_step(start, end, Step::months(1)).unwrap();
+        assert_eq!(iter.remaining(), 5);
+        let dates: Vec<_> = iter.map(|dt| dt.to_string()).collect();
+        assert_eq!(
+            dates,
+            vec![
+                "2023-01-31 12:00:00",
+                "2023-02-28 12:00:00",
+                "2023-03-31 12:00:00",
+                "2023-04-30 12:00:00",
+                "2023-05-15 00:00:00",
+            ]
+        );
+
+        let iter = NaiveDatetimeIterator::with_step(start, end, -Step::months(2)).unwrap();
+        let dates: Vec<_> = iter.map(|dt| dt.to_string()).collect();
+        assert_eq!(
+            dates,
+            vec![
+                "2023-05-15 00:00:00",
+                "2023-03-15 00:00:00",
+                "2023-01-31 12:00:00",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_calendar_step_with_duration() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let end =
+            NaiveDateTime::parse_from_str("2023-03-03 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+
+        let step = Step::months(1) + Duration::days(1);
+        let iter = NaiveDatetimeIterator::with_step(start, end, step).unwrap();
+        let dates: Vec<_> = iter.map(|dt| dt.date().to_string()).collect();
+        assert_eq!(dates, vec!["2023-01-01", "2023-02-02", "2023-03-03"]);
+
+        let step = Step::months(1) + Duration::days(-1);
+        let result = NaiveDatetimeIterator::with_step(start, end, step);
+        assert!(matches!(
+            result,
+            Err(NaiveDatetimeIterError::MixedStepSigns)
+        ));
+        let result = NaiveDatetimeIterator::with_step(start, end, Step::months(0));
+        assert!(matches!(result, Err(NaiveDatetimeIterError::ZeroStep)));
+    }
+
+    #[test]
+    fn test_flip_direction() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let end =
+            NaiveDateTime::parse_from_str("2023-01-01 05:30:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let hour = Duration::hours(1);
+
+        let mut iter = NaiveDatetimeIterator::new(start, end, hour).unwrap();
+        assert_eq!(iter.next(), Some(start));
+        assert_eq!(iter.next(), Some(start + hour));
+        assert_eq!(iter.next(), Some(start + hour * 2));
+
+        iter.flip_direction();
+        assert_eq!(iter.remaining(), 3);
+        assert_eq!(iter.next(), Some(start + hour * 2));
+        assert_eq!(iter.next(), Some(start + hour));
+
+        iter.flip_direction();
+        let rest: Vec<_> = iter.collect();
+        assert_eq!(
+            rest,
+            vec![
+                start + hour,
+                start + hour * 2,
+                start + hour * 3,
+                start + hour * 4,
+                start + hour * 5,
+                end
+            ]
+        );
+    }
+
+    #[test]
+    fn test_flip_direction_descending() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let end =
+            NaiveDateTime::parse_from_str("2023-01-03 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let step = Duration::days(-1);
+
+        let mut iter = NaiveDatetimeIterator::new(start, end, step).unwrap();
+        iter.flip_direction();
+        assert_eq!(iter.next(), None);
+
+        iter.flip_direction();
+        assert_eq!(iter.next(), Some(end));
+        assert_eq!(iter.next(), Some(end + step));
+        assert_eq!(iter.next(), Some(start));
+        assert_eq!(iter.next(), None);
+
+        iter.flip_direction();
+        assert_eq!(iter.next(), Some(start));
+        assert_eq!(iter.next(), Some(end + step));
+        assert_eq!(iter.next(), Some(end));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_from_center() {
+        let center =
+            NaiveDateTime::parse_from_str("2023-01-02 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let span = Duration::hours(5);
+        let step = Duration::hours(2);
+
+        let (forward, backward) = from_center(center, step, span).unwrap();
+        let forward: Vec<_> = forward.collect();
+        let backward: Vec<_> = backward.collect();
+        assert_eq!(forward.first(), Some(&center));
+        assert_eq!(backward.first(), Some(&center));
+        assert_eq!(forward.last(), Some(&(center + span)));
+        assert_eq!(backward.last(), Some(&(center - span)));
+        assert_eq!(forward[1], center + step);
+        assert_eq!(backward[1], center - step);
+    }
+
+    #[test]
+    fn test_from_center_ignores_signs() {
+        let center =
+            NaiveDateTime::parse_from_str("2023-01-02 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+
+        let (forward, mut backward) =
+            from_center(center, Duration::hours(-1), Duration::hours(-2)).unwrap();
+        assert_eq!(forward.collect::<Vec<_>>().len(), 3);
+        assert_eq!(backward.next_back(), Some(center - Duration::hours(2)));
+
+        let result = from_center(center, Duration::zero(), Duration::hours(2));
+        assert!(matches!(result, Err(NaiveDatetimeIterError::ZeroStep)));
+    }
+
+    #[test]
+    fn test_from_center_clamps_at_datetime_limits() {
+        let (mut forward, backward) = from_center(
+            NaiveDateTime::MAX,
+            Duration::days(365),
+            Duration::days(1000),
+        )
+        .unwrap();
+        assert_eq!(forward.next(), Some(NaiveDateTime::MAX));
+        assert_eq!(forward.next(), None);
+        assert_eq!(backward.len(), 4);
+
+        let (forward, mut backward) = from_center(
+            NaiveDateTime::MIN,
+            Duration::days(365),
+            Duration::days(1000),
+        )
+        .unwrap();
+        assert_eq!(forward.len(), 4);
+        assert_eq!(backward.next(), Some(NaiveDateTime::MIN));
+        assert_eq!(backward.next(), None);
+    }
+
+    #[test]
+    fn test_grid_eq() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let end =
+            NaiveDateTime::parse_from_str("2023-01-02 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let hourly = NaiveDatetimeIterator::new(start, end, Duration::hours(1)).unwrap();
+
+        let mut advanced = NaiveDatetimeIterator::new(start, end, Duration::minutes(60)).unwrap();
+        advanced.nth(3);
+        assert!(hourly.grid_eq(&advanced));
+
+        let descending = NaiveDatetimeIterator::new(start, end, Duration::hours(-1)).unwrap();
+        let coarser = NaiveDatetimeIterator::new(start, end, Duration::hours(2)).unwrap();
+        let shorter =
+            NaiveDatetimeIterator::new(start, end - Duration::hours(1), Duration::hours(1))
+                .unwrap();
+        assert!(!hourly.grid_eq(&descending));
+        assert!(!hourly.grid_eq(&coarser));
+        assert!(!hourly.grid_eq(&shorter));
+
+        let reversed = NaiveDatetimeIterator::new(start, end, Duration::hours(1))
+            .unwrap()
+            .reversed();
+        assert!(!hourly.grid_eq(&reversed));
+        assert!(reversed.grid_eq(&reversed.clone().reversed().reversed()));
+    }
+
+    #[test]
+    fn test_stays_exhausted() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let end =
+            NaiveDateTime::parse_from_str("2023-01-01 05:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+
+        // Ascending and descending with a clamped tail, and a single point
+        for (start, end, step, len) in [
+            (start, end, Duration::hours(2), 4),
+            (start, end, Duration::hours(-2), 4),
+            (start, start, Duration::hours(1), 1),
+        ] {
+            let mut iter = NaiveDatetimeIterator::new(start, end, step).unwrap();
+            assert_eq!(iter.by_ref().count(), len);
+            for _ in 0..3 {
+                assert_eq!(iter.next(), None);
+                assert_eq!(iter.next_back(), None);
+            }
+            assert_eq!(iter.len(), 0);
+        }
+    }
+
+    #[test]
+    fn test_clone_continues_independently() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let end =
+            NaiveDateTime::parse_from_str("2023-01-01 10:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+
+        let mut iter = NaiveDatetimeIterator::new(start, end, Duration::hours(-3)).unwrap();
+        iter.next();
+        iter.next_back();
+
+        let mut fork = iter.clone();
+        assert_eq!(fork.next(), iter.next());
+        assert_eq!(fork.collect::<Vec<_>>(), iter.collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_debug_shows_bounds_and_step() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let end =
+            NaiveDateTime::parse_from_str("2023-01-02 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+
+        let iter = NaiveDatetimeIterator::new(start, end, Duration::hours(6)).unwrap();
+        let debug = format!("{iter:?}");
+        assert!(debug.starts_with("NaiveDatetimeIterator {"));
+        assert!(debug.contains("start: 2023-01-01T00:00:00"));
+        assert!(debug.contains("end: 2023-01-02T00:00:00"));
+        assert!(debug.contains("step: Step {"));
+    }
+
+    #[test]
+    fn test_count_matches_drain() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let end =
+            NaiveDateTime::parse_from_str("2024-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+
+        for step in [
+            Duration::hours(1),
+            Duration::hours(-7),
+            Duration::days(3),
+            Duration::minutes(-1000),
+            Duration::days(400),
+        ] {
+            let mut iter = NaiveDatetimeIterator::new(start, end, step).unwrap();
+            for consumed in 0..3 {
+                let drained = iter.clone().fold(0, |n, _| n + 1);
+                assert_eq!(
+                    iter.clone().count(),
+                    drained,
+                    "step {step} after {consumed}"
+                );
+                iter.next();
+                iter.next_back();
+            }
+        }
+    }
+
+    #[test]
+    fn test_nth_matches_stepping() {
+        use crate::iter::util::splitmix64;
+
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let end =
+            NaiveDateTime::parse_from_str("2023-01-02 00:30:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let mut seed = 42;
+
+        for step in [
+            Duration::hours(1),
+            Duration::hours(-1),
+            Duration::minutes(7),
+        ] {
+            let mut fast = NaiveDatetimeIterator::new(start, end, step).unwrap();
+            let mut slow = NaiveDatetimeIterator::new(start, end, step).unwrap();
+            fast.next_back();
+            slow.next_back();
+            if step == Duration::minutes(7) {
+                fast.flip_direction();
+                slow.flip_direction();
+            }
+
+            loop {
+                let k = (splitmix64(&mut seed) % 12) as usize;
+                let mut expected = slow.next();
+                for _ in 0..k {
+                    expected = slow.next();
+                }
+                assert_eq!(fast.nth(k), expected, "step {step}, k {k}");
+                assert_eq!(fast.len(), slow.len());
+                if expected.is_none() {
+                    break;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_nth_past_end() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let end =
+            NaiveDateTime::parse_from_str("2023-01-01 05:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+
+        let mut iter = NaiveDatetimeIterator::new(start, end, Duration::hours(-1)).unwrap();
+        assert_eq!(iter.nth(5), Some(start));
+        assert_eq!(iter.nth(1), None);
+
+        let mut iter = NaiveDatetimeIterator::new(start, end, Duration::hours(1)).unwrap();
+        assert_eq!(iter.nth(usize::MAX), None);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+
+        let mut iter = NaiveDatetimeIterator::with_step(
+            NaiveDateTime::MIN,
+            NaiveDateTime::MAX,
+            Step::months(1),
+        )
+        .unwrap();
+        assert_eq!(iter.nth(usize::MAX), None);
+    }
+
+    #[test]
+    fn test_last_matches_drain() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let end =
+            NaiveDateTime::parse_from_str("2023-01-01 05:30:00", "%Y-%m-%d %H:%M:%S").unwrap();
+
+        for (end, step) in [
+            (end, Duration::hours(1)),
+            (end, Duration::hours(-1)),
+            (end, Duration::hours(2)),
+            (end, Duration::hours(6)),
+            (start, Duration::hours(1)),
+        ] {
+            let mut iter = NaiveDatetimeIterator::new(start, end, step).unwrap();
+            for _ in 0..3 {
+                let drained = iter.clone().fold(None, |_, item| Some(item));
+                assert_eq!(iter.clone().last(), drained, "step {step}");
+                iter.next();
+            }
+        }
+    }
+
+    #[test]
+    fn test_last_exclusive_and_flipped() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let end =
+            NaiveDateTime::parse_from_str("2023-01-01 05:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let hour = Duration::hours(1);
+
+        let iter = NaiveDatetimeIterator::new_exclusive(start, end, hour).unwrap();
+        assert_eq!(iter.last(), Some(end - hour));
+        let iter = NaiveDatetimeIterator::new_exclusive(start, end, -hour).unwrap();
+        assert_eq!(iter.last(), Some(start + hour));
+
+        let mut iter = NaiveDatetimeIterator::new(start, end, hour).unwrap();
+        iter.nth(2);
+        iter.flip_direction();
+        assert_eq!(iter.last(), Some(start));
+    }
+
+    #[test]
+    fn test_reversed_matches_reversed_vec() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let end =
+            NaiveDateTime::parse_from_str("2023-01-01 05:30:00", "%Y-%m-%d %H:%M:%S").unwrap();
+
+        for step in [Duration::hours(1), Duration::hours(-2), Duration::hours(7)] {
+            let mut iter = NaiveDatetimeIterator::new(start, end, step).unwrap();
+            for _ in 0..3 {
+                let mut expected: Vec<_> = iter.clone().collect();
+                expected.reverse();
+                assert_eq!(
+                    iter.clone().reversed().collect::<Vec<_>>(),
+                    expected,
+                    "step {step}"
+                );
+                assert_eq!(
+                    iter.clone().reversed().reversed().collect::<Vec<_>>(),
+                    iter.clone().collect::<Vec<_>>()
+                );
+                iter.next();
+                iter.next_back();
+            }
+        }
+    }
+
+    #[test]
+    fn test_reversed_after_flip() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let end =
+            NaiveDateTime::parse_from_str("2023-01-01 05:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let hour = Duration::hours(1);
+
+        let mut iter = NaiveDatetimeIterator::new(start, end, hour).unwrap();
+        iter.nth(2);
+        iter.flip_direction();
+        assert_eq!(
+            iter.reversed().collect::<Vec<_>>(),
+            vec![start, start + hour, start + hour * 2]
+        );
+    }
+
+    #[test]
+    fn test_with_count_even_division() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let end =
+            NaiveDateTime::parse_from_str("2023-01-02 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+
+        let iter = NaiveDatetimeIterator::with_count(start, end, 24).unwrap();
+        assert!(iter.grid_eq(&NaiveDatetimeIterator::new(start, end, Duration::hours(1)).unwrap()));
+        let points: Vec<_> = iter.collect();
+        assert_eq!(points.len(), 25);
+        assert_eq!(points[23], end - Duration::hours(1));
+        assert_eq!(points.last(), Some(&end));
+    }
+
+    #[test]
+    fn test_with_count_remainder() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let end = start + Duration::nanoseconds(10);
+
+        let iter = NaiveDatetimeIterator::with_count(start, end, 3).unwrap();
+        let floored = NaiveDatetimeIterator::new(start, end, Duration::nanoseconds(3)).unwrap();
+        assert!(!iter.grid_eq(&floored));
+        let points: Vec<_> = iter.collect();
+        assert_eq!(
+            points,
+            vec![
+                start,
+                start + Duration::nanoseconds(3),
+                start + Duration::nanoseconds(6),
+                end,
+            ]
+        );
+
+        let end = start + Duration::days(1);
+        let mut iter = NaiveDatetimeIterator::with_count(start, end, 7).unwrap();
+        assert_eq!(iter.len(), 8);
+        assert_eq!(iter.next_back(), Some(end));
+        assert_eq!(
+            iter.last(),
+            Some(start + Duration::nanoseconds(86_400_000_000_000 / 7 * 6))
+        );
+    }
+
+    #[test]
+    fn test_with_count_errors() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let end = start + Duration::seconds(1);
+
+        assert!(matches!(
+            NaiveDatetimeIterator::with_count(start, end, 0),
+            Err(NaiveDatetimeIterError::ZeroCount)
+        ));
+        assert!(matches!(
+            NaiveDatetimeIterator::with_count(end, start, 2),
+            Err(NaiveDatetimeIterError::InvalidRange { .. })
+        ));
+        assert!(matches!(
+            NaiveDatetimeIterator::with_count(start, start, 2),
+            Err(NaiveDatetimeIterError::ZeroStep)
+        ));
+    }
+
+    #[test]
+    fn test_linspace_endpoints_and_spacing() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let end = start + Duration::days(1) + Duration::nanoseconds(5);
+
+        for n in [2, 3, 7, 1000] {
+            let points: Vec<_> = NaiveDatetimeIterator::linspace(start, end, n)
+                .unwrap()
+                .collect();
+            assert_eq!(points.len(), n);
+            assert_eq!(points[0], start);
+            assert_eq!(points[n - 1], end);
+
+            let gaps: Vec<_> = points.windows(2).map(|w| w[1] - w[0]).collect();
+            let shortest = *gaps.iter().min().unwrap();
+            let longest = *gaps.iter().max().unwrap();
+            assert!(longest - shortest <= Duration::nanoseconds(1), "n {n}");
+        }
+    }
+
+    #[test]
+    fn test_linspace_small_counts() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let end = start + Duration::hours(1);
+
+        let single: Vec<_> = NaiveDatetimeIterator::linspace(start, end, 1)
+            .unwrap()
+            .collect();
+        assert_eq!(single, vec![start]);
+
+        let same: Vec<_> = NaiveDatetimeIterator::linspace(start, start, 3)
+            .unwrap()
+            .collect();
+        assert_eq!(same, vec![start; 3]);
+
+        let reversed: Vec<_> = NaiveDatetimeIterator::linspace(start, end, 3)
+            .unwrap()
+            .rev()
+            .collect();
+        assert_eq!(reversed, vec![end, start + Duration::minutes(30), start]);
+
+        assert!(matches!(
+            NaiveDatetimeIterator::linspace(start, end, 0),
+            Err(NaiveDatetimeIterError::ZeroCount)
+        ));
+        assert!(matches!(
+            NaiveDatetimeIterator::linspace(end, start, 2),
+            Err(NaiveDatetimeIterError::InvalidRange { .. })
+        ));
+    }
+
+    #[test]
+    fn test_linspace_full_range_does_not_overflow() {
+        let mut points =
+            NaiveDatetimeIterator::linspace(NaiveDateTime::MIN, NaiveDateTime::MAX, usize::MAX)
+                .unwrap();
+        assert_eq!(points.next(), Some(NaiveDateTime::MIN));
+        assert_eq!(points.next_back(), Some(NaiveDateTime::MAX));
+    }
+
+    #[test]
+    fn test_contains_matches_iteration() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let end =
+            NaiveDateTime::parse_from_str("2023-01-01 05:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let iters = [
+            NaiveDatetimeIterator::new(start, end, Duration::hours(2)).unwrap(),
+            NaiveDatetimeIterator::new(start, end, Duration::hours(-2)).unwrap(),
+            NaiveDatetimeIterator::new_exclusive(start, end, Duration::hours(2)).unwrap(),
+            NaiveDatetimeIterator::with_count(start, end, 3).unwrap(),
+        ];
+
+        for mut iter in iters {
+            iter.next();
+            let points: Vec<_> = iter.clone().collect();
+            let mut dt = start - Duration::hours(1);
+            while dt <= end + Duration::hours(1) {
+                assert_eq!(iter.contains(dt), points.contains(&dt), "{dt}");
+                dt += Duration::minutes(30);
+            }
+        }
+    }
+
+    #[test]
+    fn test_contains_aligned_and_off_grid() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let end =
+            NaiveDateTime::parse_from_str("2023-01-01 05:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let iter = NaiveDatetimeIterator::new(start, end, Duration::hours(2)).unwrap();
+
+        assert!(iter.contains(start));
+        assert!(iter.contains(start + Duration::hours(2)));
+        assert!(!iter.contains(start + Duration::minutes(61)));
+        assert!(!iter.contains(start - Duration::hours(2)));
+        assert!(!iter.contains(start + Duration::hours(6)));
+    }
+
+    #[test]
+    fn test_contains_clamped_end() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let end =
+            NaiveDateTime::parse_from_str("2023-01-01 05:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+
+        let inclusive = NaiveDatetimeIterator::new(start, end, Duration::hours(2)).unwrap();
+        let exclusive =
+            NaiveDatetimeIterator::new_exclusive(start, end, Duration::hours(2)).unwrap();
+        assert!(inclusive.contains(end));
+        assert!(!exclusive.contains(end));
+    }
+
+    #[test]
+    fn test_contains_calendar_step() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-31 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let end =
+            NaiveDateTime::parse_from_str("2023-05-15 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let iter = NaiveDatetimeIterator::with_step(start, end, Step::months(1)).unwrap();
+
+        let feb =
+            NaiveDateTime::parse_from_str("2023-02-28 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let mar =
+            NaiveDateTime::parse_from_str("2023-03-28 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        assert!(iter.contains(feb));
+        assert!(!iter.contains(mar));
+        assert!(iter.contains(end));
+        assert_eq!(
+            iter.clone().filter(|dt| iter.contains(*dt)).count(),
+            iter.len()
+        );
+    }
+
+    #[test]
+    fn test_no_drift_when_stepping_sub_second() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let step = Duration::milliseconds(333);
+        let end = start + Duration::days(12);
+
+        let (count, last) = NaiveDatetimeIterator::new_exclusive(start, end, step)
+            .unwrap()
+            .fold((0, None), |(count, _), dt| (count + 1, Some(dt)));
+        assert!(count > 3_000_000);
+        assert_eq!(last, Some(start + step * (count - 1)));
+    }
+
+    #[test]
+    fn test_rejects_grid_longer_than_usize() {
+        let start =
+            NaiveDateTime::parse_from_str("1500-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let end =
+            NaiveDateTime::parse_from_str("2200-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+
+        let result = NaiveDatetimeIterator::new(start, end, Duration::nanoseconds(1));
+        assert!(matches!(
+            result,
+            Err(NaiveDatetimeIterError::TooManyPoints { len }) if len > usize::MAX as u128
+        ));
+
+        let iter = NaiveDatetimeIterator::new(start, end, Duration::nanoseconds(2)).unwrap();
+        assert_eq!(iter.size_hint(), (iter.len(), Some(iter.len())));
+    }
+
+    #[test]
+    fn test_flip_does_not_revisit_next_back() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let end =
+            NaiveDateTime::parse_from_str("2023-01-01 05:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let hour = Duration::hours(1);
+
+        let mut iter = NaiveDatetimeIterator::new(start, end, hour).unwrap();
+        assert_eq!(iter.next_back(), Some(end));
+        iter.flip_direction();
+        iter.flip_direction();
+        assert_eq!(iter.collect::<Vec<_>>().last(), Some(&(end - hour)));
+
+        let mut iter = NaiveDatetimeIterator::new(start, end, hour).unwrap();
+        iter.nth(1);
+        iter.flip_direction();
+        assert_eq!(iter.next_back(), Some(start));
+        assert_eq!(iter.next(), Some(start + hour));
+        iter.flip_direction();
+        assert_eq!(iter.next_back(), Some(end));
+        iter.flip_direction();
+        assert_eq!(iter.next(), None);
+
+        // `start` and `end` were taken from the back and stay consumed; the
+        // cursor walks forward again from `start + hour`
+        iter.flip_direction();
+        assert_eq!(
+            iter.collect::<Vec<_>>(),
+            vec![
+                start + hour,
+                start + hour * 2,
+                start + hour * 3,
+                start + hour * 4
+            ]
+        );
+    }
 }
@@ -1,6 +1,9 @@
+use super::interval::{is_contiguous, total_idle};
 use super::naive_datetime_iter::NaiveDatetimeIterError;
 use super::naive_datetime_iter::NaiveDatetimeIterator;
+use super::util::duration_to_nanos;
 use chrono::{Duration, NaiveDateTime};
+use std::iter::FusedIterator;
 
 /// An iterator that produces consecutive datetime ranges
 ///
@@ -36,12 +39,50 @@ use chrono::{Duration, NaiveDateTime};
 /// assert_eq!(iter.next(), Some((end + step, end)));
 /// assert_eq!(iter.next(), Some((start, end + step)));
 /// ```
+#[derive(Debug, Clone)]
 pub struct NaiveDatetimeRangeIterator {
     datetime_iter: NaiveDatetimeIterator,
     current: Option<NaiveDateTime>,
+    /// The shared boundary of the last range yielded from the back
+    current_back: Option<NaiveDateTime>,
     asc: bool,
 }
 
+/// Returns the number of ranges formed by `n` consecutive points
+///
+/// Each range joins two neighbouring points, so `n` points give `n - 1`
+/// ranges, and a single point or none gives no ranges. A
+/// [`NaiveDatetimeRangeIterator`] always yields this many ranges for the
+/// points its [`NaiveDatetimeIterator`] would yield.
+///
+/// # Examples
+/// ```
+/// use chrono_kit::iter::{points_to_ranges, NaiveDatetimeIterator, NaiveDatetimeRangeIterator};
+/// use chrono::{NaiveDateTime, Duration};
+///
+/// let start = NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+/// let end = NaiveDateTime::parse_from_str("2023-01-02 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+///
+/// let points = NaiveDatetimeIterator::new(start, end, Duration::hours(1)).unwrap().count();
+/// let ranges = NaiveDatetimeRangeIterator::new(start, end, Duration::hours(1)).unwrap().count();
+/// assert_eq!(points_to_ranges(points), ranges);
+/// ```
+pub fn points_to_ranges(n: usize) -> usize {
+    n.saturating_sub(1)
+}
+
+/// Returns the number of points needed to form `n` contiguous ranges
+///
+/// The inverse of [`points_to_ranges`]: `n` ranges share their inner
+/// endpoints, so they need `n + 1` points. Zero ranges map to the single
+/// point of a grid whose `start` equals its `end`.
+///
+/// # Panics
+/// Panics if `n` is `usize::MAX`.
+pub fn ranges_to_points(n: usize) -> usize {
+    n.checked_add(1).expect("point count overflows usize")
+}
+
 impl NaiveDatetimeRangeIterator {
     /// Creates a new DatetimeRangeIterator
     ///
@@ -64,9 +105,355 @@ impl NaiveDatetimeRangeIterator {
         Ok(NaiveDatetimeRangeIterator {
             datetime_iter,
             current: None,
+            current_back: None,
+            asc: step > Duration::zero(),
+        })
+    }
+
+    /// Creates a new DatetimeRangeIterator whose endpoints must lie on the step grid
+    ///
+    /// See [`NaiveDatetimeIterator::new_strict_aligned`] for the alignment rules.
+    ///
+    /// # Errors
+    /// Returns `DatetimeIterError` if:
+    /// - `step` is zero
+    /// - `start` and `end` don't form a valid range for the given step
+    /// - `start` or `end` is not an epoch-aligned multiple of `step`
+    pub fn new_strict_aligned(
+        start: NaiveDateTime,
+        end: NaiveDateTime,
+        step: Duration,
+    ) -> Result<Self, NaiveDatetimeIterError> {
+        let datetime_iter = NaiveDatetimeIterator::new_strict_aligned(start, end, step)?;
+
+        Ok(NaiveDatetimeRangeIterator {
+            datetime_iter,
+            current: None,
+            current_back: None,
             asc: step > Duration::zero(),
         })
     }
+
+    /// Returns the number of ranges left to yield, computed without iterating
+    ///
+    /// The datetime grid always fits in a `usize`, and there is one range
+    /// fewer than datetimes, so the count always fits too.
+    fn remaining(&self) -> usize {
+        let points = self.datetime_iter.remaining_len()
+            + u128::from(self.current.is_some())
+            + u128::from(self.current_back.is_some());
+        points.saturating_sub(1) as usize
+    }
+
+    /// Returns the position of the range containing `dt` among the remaining ranges
+    ///
+    /// Ranges are treated as half-open `[start, end)`, except that the range
+    /// touching the original `end` also contains it. The index is relative to
+    /// the ranges not yet yielded, so `iter.nth(index)` returns the containing
+    /// range. Returns `None` if `dt` is not covered by any remaining range.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono_kit::iter::NaiveDatetimeRangeIterator;
+    /// use chrono::{NaiveDateTime, Duration};
+    ///
+    /// let start = NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let end = NaiveDateTime::parse_from_str("2023-01-02 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    ///
+    /// let iter = NaiveDatetimeRangeIterator::new(start, end, Duration::hours(1)).unwrap();
+    /// assert_eq!(iter.index_of(start + Duration::minutes(150)), Some(2));
+    /// assert_eq!(iter.index_of(end), Some(23));
+    /// assert_eq!(iter.index_of(end + Duration::hours(1)), None);
+    /// ```
+    pub fn index_of(&self, dt: NaiveDateTime) -> Option<usize> {
+        let ranges = self.remaining();
+        if ranges == 0 {
+            return None;
+        }
+
+        let (start, end) = self.datetime_iter.original_bounds();
+        let step = duration_to_nanos(self.datetime_iter.step()).abs();
+        let index = if self.asc {
+            let first = self.current.unwrap_or(start);
+            if dt < first || dt > end || self.current_back.is_some_and(|last| dt >= last) {
+                return None;
+            }
+            duration_to_nanos(dt - first) / step
+        } else {
+            let first = self.current.unwrap_or(end);
+            let last = self.current_back.unwrap_or(start);
+            if dt < last || dt > first {
+                return None;
+            }
+            let distance = duration_to_nanos(first - dt);
+            (distance + step - 1) / step - 1
+        };
+
+        let index = usize::try_from(index.max(0)).unwrap_or(usize::MAX);
+        Some(index.min(ranges - 1))
+    }
+
+    /// Returns the underlying datetime iterator
+    pub(crate) fn datetime_iter(&self) -> &NaiveDatetimeIterator {
+        &self.datetime_iter
+    }
+
+    /// Returns `true` if ranges are yielded in ascending order
+    pub(crate) fn is_ascending(&self) -> bool {
+        self.asc
+    }
+
+    /// Returns `true` if the remaining ranges tile their interval with no gaps or overlaps
+    ///
+    /// Ranges produced directly by this iterator are always contiguous; see
+    /// [`is_contiguous`](crate::iter::is_contiguous) for checking ranges after
+    /// filtering or masking.
+    pub fn is_contiguous(self) -> bool {
+        is_contiguous(self)
+    }
+
+    /// Returns the total time between consecutive remaining ranges
+    ///
+    /// Ranges produced directly by this iterator are contiguous, so this is
+    /// zero; see [`total_idle`](crate::iter::total_idle) for measuring gaps
+    /// after filtering or masking.
+    pub fn total_idle(self) -> Duration {
+        total_idle(self)
+    }
+
+    /// Yields the start of each range
+    ///
+    /// Unlike the underlying datetime iterator this never yields the terminal
+    /// `end` of an ascending iteration, since no range starts there.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono_kit::iter::NaiveDatetimeRangeIterator;
+    /// use chrono::{NaiveDateTime, Duration};
+    ///
+    /// let start = NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let end = NaiveDateTime::parse_from_str("2023-01-03 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let step = Duration::days(1);
+    ///
+    /// let iter = NaiveDatetimeRangeIterator::new(start, end, step).unwrap();
+    /// assert_eq!(iter.starts().collect::<Vec<_>>(), vec![start, start + step]);
+    /// ```
+    pub fn starts(self) -> impl Iterator<Item = NaiveDateTime> {
+        self.map(|(start, _)| start)
+    }
+
+    /// Yields the end of each range
+    ///
+    /// For ascending iteration this includes the clamped terminal `end` but not
+    /// the initial `start`, so it equals [`starts`](Self::starts) shifted by one
+    /// with `end` appended.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono_kit::iter::NaiveDatetimeRangeIterator;
+    /// use chrono::{NaiveDateTime, Duration};
+    ///
+    /// let start = NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let end = NaiveDateTime::parse_from_str("2023-01-03 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let step = Duration::days(1);
+    ///
+    /// let iter = NaiveDatetimeRangeIterator::new(start, end, step).unwrap();
+    /// assert_eq!(iter.ends().collect::<Vec<_>>(), vec![start + step, end]);
+    /// ```
+    pub fn ends(self) -> impl Iterator<Item = NaiveDateTime> {
+        self.map(|(_, end)| end)
+    }
+
+    /// Annotates each range with the total duration of all ranges before it
+    ///
+    /// The first range has an offset of zero. Since the ranges are contiguous,
+    /// the offset of an ascending range equals its distance from the first start.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono_kit::iter::NaiveDatetimeRangeIterator;
+    /// use chrono::{NaiveDateTime, Duration};
+    ///
+    /// let start = NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let end = NaiveDateTime::parse_from_str("2023-01-03 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let step = Duration::days(1);
+    ///
+    /// let mut iter = NaiveDatetimeRangeIterator::new(start, end, step).unwrap().with_cumulative_offset();
+    /// assert_eq!(iter.next(), Some((start, start + step, Duration::zero())));
+    /// assert_eq!(iter.next(), Some((start + step, end, step)));
+    /// ```
+    pub fn with_cumulative_offset(
+        self,
+    ) -> impl Iterator<Item = (NaiveDateTime, NaiveDateTime, Duration)> {
+        let mut offset = Duration::zero();
+        self.map(move |(start, end)| {
+            let range_offset = offset;
+            offset += end - start;
+            (start, end, range_offset)
+        })
+    }
+
+    /// Pads each range by `margin` on both sides
+    ///
+    /// Yields `(start - margin, end + margin)` for each range, clamped to
+    /// `NaiveDateTime::MIN` and `NaiveDateTime::MAX` instead of overflowing.
+    /// Expanded ranges overlap their neighbours whenever `margin` is positive;
+    /// they are yielded as-is rather than merged.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono_kit::iter::NaiveDatetimeRangeIterator;
+    /// use chrono::{NaiveDateTime, Duration};
+    ///
+    /// let start = NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let end = NaiveDateTime::parse_from_str("2023-01-01 01:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let margin = Duration::minutes(5);
+    ///
+    /// let mut iter = NaiveDatetimeRangeIterator::new(start, end, Duration::hours(1)).unwrap().expand(margin);
+    /// assert_eq!(iter.next(), Some((start - margin, end + margin)));
+    /// ```
+    pub fn expand(self, margin: Duration) -> impl Iterator<Item = (NaiveDateTime, NaiveDateTime)> {
+        self.map(move |(start, end)| {
+            (
+                start
+                    .checked_sub_signed(margin)
+                    .unwrap_or(NaiveDateTime::MIN),
+                end.checked_add_signed(margin).unwrap_or(NaiveDateTime::MAX),
+            )
+        })
+    }
+
+    /// Merges every `k` consecutive ranges into one
+    ///
+    /// The ranges skipped over are folded into the kept one, so the merged
+    /// ranges still cover the same interval without gaps. A trailing group
+    /// shorter than `k` is merged as well.
+    ///
+    /// # Errors
+    /// Returns `NaiveDatetimeIterError::ZeroCount` if `k` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono_kit::iter::NaiveDatetimeRangeIterator;
+    /// use chrono::{NaiveDateTime, Duration};
+    ///
+    /// let start = NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let end = NaiveDateTime::parse_from_str("2023-01-04 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let step = Duration::days(1);
+    ///
+    /// let mut iter = NaiveDatetimeRangeIterator::new(start, end, step).unwrap().every(2).unwrap();
+    /// assert_eq!(iter.next(), Some((start, start + step * 2)));
+    /// assert_eq!(iter.next(), Some((start + step * 2, end)));
+    /// ```
+    pub fn every(
+        self,
+        k: usize,
+    ) -> Result<impl Iterator<Item = (NaiveDateTime, NaiveDateTime)>, NaiveDatetimeIterError> {
+        if k == 0 {
+            return Err(NaiveDatetimeIterError::ZeroCount);
+        }
+
+        let mut ranges = self;
+        Ok(std::iter::from_fn(move || {
+            let first = ranges.next()?;
+            Some(
+                ranges
+                    .by_ref()
+                    .take(k - 1)
+                    .fold(first, |(start, end), (s, e)| (start.min(s), end.max(e))),
+            )
+        }))
+    }
+
+    /// Merges runs of consecutive ranges that map to the same key
+    ///
+    /// `key` is called once per range. Each run of adjacent ranges with equal
+    /// keys is yielded as a single range spanning the whole run; ranges with
+    /// the same key that are separated by a different key stay apart.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono_kit::iter::NaiveDatetimeRangeIterator;
+    /// use chrono::{NaiveDateTime, Duration};
+    ///
+    /// let start = NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let end = NaiveDateTime::parse_from_str("2023-01-03 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let day = Duration::days(1);
+    ///
+    /// let iter = NaiveDatetimeRangeIterator::new(start, end, Duration::hours(6)).unwrap();
+    /// let days: Vec<_> = iter.coalesce_by(|(start, _)| start.date()).collect();
+    /// assert_eq!(days, vec![(start, start + day), (start + day, end)]);
+    /// ```
+    pub fn coalesce_by<K: Eq>(
+        self,
+        key: impl Fn((NaiveDateTime, NaiveDateTime)) -> K,
+    ) -> impl Iterator<Item = (NaiveDateTime, NaiveDateTime)> {
+        let mut ranges = self;
+        let mut pending = None;
+
+        std::iter::from_fn(move || {
+            let (mut merged, run_key) = match pending.take() {
+                Some(entry) => entry,
+                None => {
+                    let range = ranges.next()?;
+                    (range, key(range))
+                }
+            };
+
+            for range in ranges.by_ref() {
+                let range_key = key(range);
+                if range_key != run_key {
+                    pending = Some((range, range_key));
+                    break;
+                }
+                merged = (merged.0.min(range.0), merged.1.max(range.1));
+            }
+            Some(merged)
+        })
+    }
+
+    /// Converts the ranges into non-overlapping, inclusive ranges
+    ///
+    /// Each range ends exactly one nanosecond before the start of the range that
+    /// follows it on the timeline, so no instant belongs to two ranges. The range
+    /// touching the original `end` keeps it as its upper bound.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono_kit::iter::NaiveDatetimeRangeIterator;
+    /// use chrono::{NaiveDateTime, Duration};
+    ///
+    /// let start = NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let end = NaiveDateTime::parse_from_str("2023-01-03 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let step = Duration::days(1);
+    ///
+    /// let mut iter = NaiveDatetimeRangeIterator::new(start, end, step).unwrap().gapless_exclusive();
+    /// assert_eq!(iter.next(), Some((start, start + step - Duration::nanoseconds(1))));
+    /// assert_eq!(iter.next(), Some((start + step, end)));
+    /// ```
+    pub fn gapless_exclusive(self) -> impl Iterator<Item = (NaiveDateTime, NaiveDateTime)> {
+        let asc = self.asc;
+        // For descending iteration only the very first range touches `end`,
+        // and for ascending iteration only the last, unless taken from the back
+        let mut touches_end = !asc && self.current.is_none();
+        let end_remaining = self.current_back.is_none();
+        let mut ranges = self.peekable();
+
+        std::iter::from_fn(move || {
+            let (start, end) = ranges.next()?;
+            let keep_end = if asc {
+                end_remaining && ranges.peek().is_none()
+            } else {
+                std::mem::take(&mut touches_end)
+            };
+
+            if keep_end {
+                Some((start, end))
+            } else {
+                Some((start, end - Duration::nanoseconds(1)))
+            }
+        })
+    }
 }
 
 impl Iterator for NaiveDatetimeRangeIterator {
@@ -82,7 +469,11 @@ impl Iterator for NaiveDatetimeRangeIterator {
             }
         };
 
-        let end = self.datetime_iter.next()?;
+        // Once the datetimes run out, the last range ends where `next_back` stopped
+        let end = self
+            .datetime_iter
+            .next()
+            .or_else(|| self.current_back.take())?;
         self.current = Some(end);
         if self.asc {
             Some((start, end))
@@ -90,6 +481,54 @@ impl Iterator for NaiveDatetimeRangeIterator {
             Some((end, start))
         }
     }
+
+    /// Returns the exact number of remaining ranges, computed without iterating
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.remaining();
+        (len, Some(len))
+    }
+
+    /// Returns the number of remaining ranges, computed without iterating
+    ///
+    /// This is one less than the number of remaining datetimes, or zero.
+    fn count(self) -> usize {
+        self.remaining()
+    }
+
+    /// Returns the final range in constant time, without iterating
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl ExactSizeIterator for NaiveDatetimeRangeIterator {}
+
+impl FusedIterator for NaiveDatetimeRangeIterator {}
+
+impl DoubleEndedIterator for NaiveDatetimeRangeIterator {
+    /// Yields from the far end: the latest range for ascending steps and the earliest for descending ones
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let end = match self.current_back {
+            Some(dt) => dt,
+            None => {
+                let last = self.datetime_iter.next_back()?;
+                self.current_back = Some(last);
+                last
+            }
+        };
+
+        // Once the datetimes run out, the last range starts where `next` stopped
+        let start = self
+            .datetime_iter
+            .next_back()
+            .or_else(|| self.current.take())?;
+        self.current_back = Some(start);
+        if self.asc {
+            Some((start, end))
+        } else {
+            Some((end, start))
+        }
+    }
 }
 
 #[cfg(test)]
@@ -152,6 +591,406 @@ mod tests {
         assert_eq!(iter.next(), None);
     }
 
+    #[test]
+    fn test_gapless_exclusive_ascending() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let end =
+            NaiveDateTime::parse_from_str("2023-01-01 12:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let step = Duration::hours(5);
+
+        let ranges: Vec<_> = NaiveDatetimeRangeIterator::new(start, end, step)
+            .unwrap()
+            .gapless_exclusive()
+            .collect();
+        let one_ns = Duration::nanoseconds(1);
+        assert_eq!(
+            ranges,
+            vec![
+                (start, start + step - one_ns),
+                (start + step, start + step * 2 - one_ns),
+                (start + step * 2, end),
+            ]
+        );
+        for pair in ranges.windows(2) {
+            assert!(pair[0].1 < pair[1].0);
+            assert_eq!(pair[0].1 + one_ns, pair[1].0);
+        }
+    }
+
+    #[test]
+    fn test_gapless_exclusive_descending() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let end =
+            NaiveDateTime::parse_from_str("2023-01-03 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let step = Duration::days(-1);
+
+        let ranges: Vec<_> = NaiveDatetimeRangeIterator::new(start, end, step)
+            .unwrap()
+            .gapless_exclusive()
+            .collect();
+        let one_ns = Duration::nanoseconds(1);
+        assert_eq!(
+            ranges,
+            vec![(end + step, end), (start, end + step - one_ns)]
+        );
+        for pair in ranges.windows(2) {
+            assert!(pair[1].1 < pair[0].0);
+        }
+    }
+
+    #[test]
+    fn test_starts() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let end =
+            NaiveDateTime::parse_from_str("2023-01-01 12:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let step = Duration::hours(5);
+
+        let starts: Vec<_> = NaiveDatetimeRangeIterator::new(start, end, step)
+            .unwrap()
+            .starts()
+            .collect();
+        assert_eq!(starts, vec![start, start + step, start + step * 2]);
+        assert!(!starts.contains(&end));
+
+        let starts: Vec<_> = NaiveDatetimeRangeIterator::new(start, end, -step)
+            .unwrap()
+            .starts()
+            .collect();
+        assert_eq!(starts, vec![end - step, end - step * 2, start]);
+    }
+
+    #[test]
+    fn test_ends() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let end =
+            NaiveDateTime::parse_from_str("2023-01-01 12:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let step = Duration::hours(5);
+
+        let starts: Vec<_> = NaiveDatetimeRangeIterator::new(start, end, step)
+            .unwrap()
+            .starts()
+            .collect();
+        let ends: Vec<_> = NaiveDatetimeRangeIterator::new(start, end, step)
+            .unwrap()
+            .ends()
+            .collect();
+        assert_eq!(ends, vec![start + step, start + step * 2, end]);
+        assert_eq!(ends[..ends.len() - 1], starts[1..]);
+        assert!(!ends.contains(&start));
+    }
+
+    #[test]
+    fn test_with_cumulative_offset() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let end =
+            NaiveDateTime::parse_from_str("2023-01-01 12:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let step = Duration::hours(5);
+
+        let ranges: Vec<_> = NaiveDatetimeRangeIterator::new(start, end, step)
+            .unwrap()
+            .with_cumulative_offset()
+            .collect();
+        assert_eq!(
+            ranges,
+            vec![
+                (start, start + step, Duration::zero()),
+                (start + step, start + step * 2, step),
+                (start + step * 2, end, step * 2),
+            ]
+        );
+
+        let ranges: Vec<_> = NaiveDatetimeRangeIterator::new(start, end, -step)
+            .unwrap()
+            .with_cumulative_offset()
+            .collect();
+        assert_eq!(
+            ranges,
+            vec![
+                (end - step, end, Duration::zero()),
+                (end - step * 2, end - step, step),
+                (start, end - step * 2, step * 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_index_of_round_trips_with_nth() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let end =
+            NaiveDateTime::parse_from_str("2023-01-01 12:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+
+        for step in [Duration::hours(5), Duration::hours(-5), Duration::hours(4)] {
+            let mut probe = start;
+            while probe <= end {
+                let mut iter = NaiveDatetimeRangeIterator::new(start, end, step).unwrap();
+                let index = iter.index_of(probe).unwrap();
+                let (range_start, range_end) = iter.nth(index).unwrap();
+                assert!(range_start <= probe && probe <= range_end);
+                assert!(probe < range_end || probe == end);
+                probe += Duration::minutes(30);
+            }
+        }
+    }
+
+    #[test]
+    fn test_index_of_after_partial_iteration() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let end =
+            NaiveDateTime::parse_from_str("2023-01-01 12:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+
+        let mut iter = NaiveDatetimeRangeIterator::new(start, end, Duration::hours(1)).unwrap();
+        iter.next();
+        iter.next();
+        assert_eq!(iter.index_of(start), None);
+        assert_eq!(iter.index_of(start + Duration::hours(2)), Some(0));
+        assert_eq!(iter.index_of(end), Some(9));
+
+        let mut iter = NaiveDatetimeRangeIterator::new(start, end, Duration::hours(-1)).unwrap();
+        iter.next();
+        assert_eq!(iter.index_of(end), None);
+        assert_eq!(iter.index_of(end - Duration::hours(1)), Some(0));
+        assert_eq!(iter.index_of(start), Some(10));
+        assert_eq!(iter.index_of(start - Duration::hours(1)), None);
+    }
+
+    #[test]
+    fn test_is_contiguous() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let end =
+            NaiveDateTime::parse_from_str("2023-01-01 12:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+
+        let iter = NaiveDatetimeRangeIterator::new(start, end, Duration::hours(5)).unwrap();
+        assert!(iter.is_contiguous());
+        let iter = NaiveDatetimeRangeIterator::new(start, end, Duration::hours(-5)).unwrap();
+        assert!(iter.is_contiguous());
+
+        let iter = NaiveDatetimeRangeIterator::new(start, end, Duration::hours(1)).unwrap();
+        let masked = iter.filter(|(range_start, _)| *range_start != start + Duration::hours(3));
+        assert!(!is_contiguous(masked));
+    }
+
+    #[test]
+    fn test_new_strict_aligned() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let end =
+            NaiveDateTime::parse_from_str("2023-01-01 12:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+
+        assert!(
+            NaiveDatetimeRangeIterator::new_strict_aligned(start, end, Duration::hours(1)).is_ok()
+        );
+        let end = end + Duration::minutes(30);
+        let result = NaiveDatetimeRangeIterator::new_strict_aligned(start, end, Duration::hours(1));
+        assert!(matches!(
+            result,
+            Err(NaiveDatetimeIterError::MisalignedEnd { .. })
+        ));
+    }
+
+    #[test]
+    fn test_expand() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let end =
+            NaiveDateTime::parse_from_str("2023-01-01 02:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let margin = Duration::minutes(10);
+
+        let expanded: Vec<_> = NaiveDatetimeRangeIterator::new(start, end, Duration::hours(1))
+            .unwrap()
+            .expand(margin)
+            .collect();
+        assert_eq!(
+            expanded,
+            vec![
+                (start - margin, start + Duration::minutes(70)),
+                (start + Duration::minutes(50), end + margin),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_expand_clamps_at_bounds() {
+        let start = NaiveDateTime::MIN;
+        let end = NaiveDateTime::MAX;
+
+        let expanded: Vec<_> = NaiveDatetimeRangeIterator::new(start, end, end - start)
+            .unwrap()
+            .expand(Duration::days(1))
+            .collect();
+        assert_eq!(expanded, vec![(start, end)]);
+    }
+
+    #[test]
+    fn test_every_preserves_coverage() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let end =
+            NaiveDateTime::parse_from_str("2023-01-01 05:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+
+        let merged: Vec<_> = NaiveDatetimeRangeIterator::new(start, end, Duration::hours(1))
+            .unwrap()
+            .every(2)
+            .unwrap()
+            .collect();
+        assert_eq!(
+            merged,
+            vec![
+                (start, start + Duration::hours(2)),
+                (start + Duration::hours(2), start + Duration::hours(4)),
+                (start + Duration::hours(4), end),
+            ]
+        );
+        assert!(crate::iter::is_contiguous(merged.iter().copied()));
+
+        let merged: Vec<_> = NaiveDatetimeRangeIterator::new(start, end, Duration::hours(-2))
+            .unwrap()
+            .every(2)
+            .unwrap()
+            .collect();
+        assert_eq!(
+            merged,
+            vec![
+                (start + Duration::hours(1), end),
+                (start, start + Duration::hours(1)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_coalesce_by_calendar_day() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-01 20:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let end =
+            NaiveDateTime::parse_from_str("2023-01-03 02:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let midnight = |day: &str| {
+            NaiveDateTime::parse_from_str(&format!("{day} 00:00:00"), "%Y-%m-%d %H:%M:%S").unwrap()
+        };
+
+        let days: Vec<_> = NaiveDatetimeRangeIterator::new(start, end, Duration::hours(4))
+            .unwrap()
+            .coalesce_by(|(start, _)| start.date())
+            .collect();
+        assert_eq!(
+            days,
+            vec![
+                (start, midnight("2023-01-02")),
+                (midnight("2023-01-02"), midnight("2023-01-03")),
+                (midnight("2023-01-03"), end),
+            ]
+        );
+
+        let days: Vec<_> = NaiveDatetimeRangeIterator::new(start, end, Duration::hours(-4))
+            .unwrap()
+            .coalesce_by(|(start, _)| start.date())
+            .collect();
+        assert_eq!(
+            days,
+            vec![
+                (midnight("2023-01-02") + Duration::hours(2), end),
+                (start, midnight("2023-01-02") + Duration::hours(2)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_every_zero_rejected() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let iter =
+            NaiveDatetimeRangeIterator::new(start, start + Duration::hours(2), Duration::hours(1))
+                .unwrap();
+
+        assert!(matches!(
+            iter.every(0),
+            Err(NaiveDatetimeIterError::ZeroCount)
+        ));
+    }
+
+    #[test]
+    fn test_next_back_interleaved() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let end =
+            NaiveDateTime::parse_from_str("2023-01-01 10:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+
+        for step in [Duration::hours(3), Duration::hours(-3), Duration::hours(1)] {
+            let expected: Vec<_> = NaiveDatetimeRangeIterator::new(start, end, step)
+                .unwrap()
+                .collect();
+
+            for pattern in [0b0101, 0b0011, 0b1110, 0b1111] {
+                let mut iter = NaiveDatetimeRangeIterator::new(start, end, step).unwrap();
+                let (mut front, mut back) = (Vec::new(), Vec::new());
+                for i in 0.. {
+                    let item = if pattern >> (i % 4) & 1 == 1 {
+                        iter.next_back().map(|range| back.push(range))
+                    } else {
+                        iter.next().map(|range| front.push(range))
+                    };
+                    if item.is_none() {
+                        break;
+                    }
+                }
+                assert_eq!(iter.next(), None);
+                assert_eq!(iter.next_back(), None);
+
+                front.extend(back.into_iter().rev());
+                assert_eq!(front, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_index_of_after_next_back() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let end =
+            NaiveDateTime::parse_from_str("2023-01-01 04:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+
+        let mut iter = NaiveDatetimeRangeIterator::new(start, end, Duration::hours(1)).unwrap();
+        iter.next_back();
+        assert_eq!(iter.index_of(start + Duration::minutes(150)), Some(2));
+        assert_eq!(iter.index_of(start + Duration::hours(3)), None);
+        assert_eq!(iter.index_of(end), None);
+
+        let mut iter = NaiveDatetimeRangeIterator::new(start, end, Duration::hours(-1)).unwrap();
+        iter.next_back();
+        assert_eq!(iter.index_of(start + Duration::minutes(90)), Some(2));
+        assert_eq!(iter.index_of(start + Duration::minutes(30)), None);
+    }
+
+    #[test]
+    fn test_point_and_range_counts_agree() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+
+        for (end, step) in [
+            (start + Duration::hours(6), Duration::hours(2)),
+            (start + Duration::hours(6), Duration::hours(-3)),
+            (start + Duration::hours(7), Duration::hours(2)),
+            (start, Duration::hours(1)),
+        ] {
+            let points = NaiveDatetimeIterator::new(start, end, step).unwrap();
+            let mut ranges = NaiveDatetimeRangeIterator::new(start, end, step).unwrap();
+            assert_eq!(ranges.len(), points_to_ranges(points.len()));
+            assert_eq!(ranges_to_points(ranges.len()), points.len());
+            assert_eq!(points.count(), ranges.len() + 1);
+
+            let total = ranges.len();
+            ranges.next();
+            assert_eq!(ranges.len(), total.saturating_sub(1));
+            assert_eq!(ranges.count(), total.saturating_sub(1));
+        }
+    }
+
     #[test]
     fn test_zero_step_error() {
         let start =
@@ -178,4 +1017,156 @@ mod tests {
             Err(NaiveDatetimeIterError::InvalidRange { .. })
         ));
     }
+
+    #[test]
+    fn test_stays_exhausted() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let end =
+            NaiveDateTime::parse_from_str("2023-01-01 05:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+
+        // Ascending and descending with a clamped tail, a single range, and a single point
+        for (start, end, step, len) in [
+            (start, end, Duration::hours(2), 3),
+            (start, end, Duration::hours(-2), 3),
+            (start, end, Duration::hours(5), 1),
+            (start, start, Duration::hours(1), 0),
+        ] {
+            let mut iter = NaiveDatetimeRangeIterator::new(start, end, step).unwrap();
+            assert_eq!(iter.by_ref().count(), len);
+            for _ in 0..3 {
+                assert_eq!(iter.next(), None);
+                assert_eq!(iter.next_back(), None);
+            }
+            assert_eq!(iter.len(), 0);
+        }
+    }
+
+    #[test]
+    fn test_stays_exhausted_when_ends_meet() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let end =
+            NaiveDateTime::parse_from_str("2023-01-01 03:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+
+        let mut iter = NaiveDatetimeRangeIterator::new(start, end, Duration::hours(1)).unwrap();
+        assert!(iter.next().is_some());
+        assert!(iter.next_back().is_some());
+        assert!(iter.next().is_some());
+        for _ in 0..3 {
+            assert_eq!(iter.next(), None);
+            assert_eq!(iter.next_back(), None);
+        }
+    }
+
+    #[test]
+    fn test_clone_continues_independently() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let end =
+            NaiveDateTime::parse_from_str("2023-01-02 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+
+        let mut iter = NaiveDatetimeRangeIterator::new(start, end, Duration::hours(5)).unwrap();
+        iter.next();
+
+        let fork = iter.clone();
+        let original: Vec<_> = iter.collect();
+        assert_eq!(original.len(), 4);
+        assert_eq!(fork.collect::<Vec<_>>(), original);
+    }
+
+    #[test]
+    fn test_debug_shows_state() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let end =
+            NaiveDateTime::parse_from_str("2023-01-02 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+
+        let mut iter = NaiveDatetimeRangeIterator::new(start, end, Duration::hours(-6)).unwrap();
+        iter.next();
+        let debug = format!("{iter:?}");
+        assert!(debug.contains("current: Some(2023-01-01T18:00:00)"));
+        assert!(debug.contains("asc: false"));
+        assert!(debug.contains("start: 2023-01-01T00:00:00"));
+    }
+
+    #[test]
+    fn test_total_idle_is_zero() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let end =
+            NaiveDateTime::parse_from_str("2023-01-01 05:30:00", "%Y-%m-%d %H:%M:%S").unwrap();
+
+        let mut iter = NaiveDatetimeRangeIterator::new(start, end, Duration::hours(2)).unwrap();
+        iter.next_back();
+        assert_eq!(iter.total_idle(), Duration::zero());
+    }
+
+    #[test]
+    fn test_count_matches_drain() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let end =
+            NaiveDateTime::parse_from_str("2024-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+
+        for step in [
+            Duration::hours(1),
+            Duration::hours(-7),
+            Duration::days(3),
+            Duration::minutes(-1000),
+            Duration::days(400),
+        ] {
+            let mut iter = NaiveDatetimeRangeIterator::new(start, end, step).unwrap();
+            for consumed in 0..3 {
+                let drained = iter.clone().fold(0, |n, _| n + 1);
+                assert_eq!(
+                    iter.clone().count(),
+                    drained,
+                    "step {step} after {consumed}"
+                );
+                iter.next();
+                iter.next_back();
+            }
+        }
+    }
+
+    #[test]
+    fn test_last_matches_drain() {
+        let start =
+            NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let end =
+            NaiveDateTime::parse_from_str("2023-01-01 05:30:00", "%Y-%m-%d %H:%M:%S").unwrap();
+
+        for (end, step) in [
+            (end, Duration::hours(1)),
+            (end, Duration::hours(-1)),
+            (end, Duration::hours(2)),
+            (end, Duration::hours(6)),
+            (start, Duration::hours(1)),
+        ] {
+            let mut iter = NaiveDatetimeRangeIterator::new(start, end, step).unwrap();
+            for _ in 0..3 {
+                let drained = iter.clone().fold(None, |_, item| Some(item));
+                assert_eq!(iter.clone().last(), drained, "step {step}");
+                iter.next();
+            }
+        }
+    }
+
+    #[test]
+    fn test_len_of_grid_near_usize_max() {
+        let start =
+            NaiveDateTime::parse_from_str("1700-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let end =
+            NaiveDateTime::parse_from_str("2200-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let step = Duration::nanoseconds(1);
+
+        let points = NaiveDatetimeIterator::new(start, end, step).unwrap().len();
+        let mut iter = NaiveDatetimeRangeIterator::new(start, end, step).unwrap();
+        assert_eq!(iter.size_hint(), (points - 1, Some(points - 1)));
+
+        iter.next();
+        iter.next_back();
+        assert_eq!(iter.len(), points - 3);
+    }
 }
@@ -0,0 +1,211 @@
+use super::naive_datetime_iter::{NaiveDatetimeIterError, NaiveDatetimeIterator};
+use super::util::{duration_to_nanos, nanos_to_duration};
+use chrono::{Duration, NaiveDateTime};
+
+/// Returns the datetime at fraction `f` of the way from `start` to `end`
+///
+/// `f` must be finite; the offset is rounded to the nearest nanosecond.
+fn interpolate(start: NaiveDateTime, end: NaiveDateTime, f: f64) -> NaiveDateTime {
+    let span = duration_to_nanos(end - start) as f64;
+    let offset = nanos_to_duration((span * f).round() as i128).unwrap();
+    (start + offset).min(end)
+}
+
+impl NaiveDatetimeIterator {
+    /// Returns the datetimes at the given percentiles of the iterator's range
+    ///
+    /// Each percentile `p` maps to `start + (end - start) * p`, using the bounds
+    /// given at construction regardless of step direction or iteration progress.
+    /// Results are rounded to the nearest nanosecond and need not lie on the
+    /// step grid.
+    ///
+    /// # Errors
+    /// Returns `NaiveDatetimeIterError::InvalidFraction` if any percentile is
+    /// outside `[0, 1]` or not a number.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono_kit::iter::NaiveDatetimeIterator;
+    /// use chrono::{NaiveDateTime, Duration};
+    ///
+    /// let start = NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let end = NaiveDateTime::parse_from_str("2023-01-02 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    ///
+    /// let iter = NaiveDatetimeIterator::new(start, end, Duration::hours(1)).unwrap();
+    /// let points = iter.at_percentiles(&[0.0, 0.5, 1.0]).unwrap();
+    /// assert_eq!(points, vec![start, start + Duration::hours(12), end]);
+    /// ```
+    pub fn at_percentiles(&self, ps: &[f64]) -> Result<Vec<NaiveDateTime>, NaiveDatetimeIterError> {
+        if let Some(&value) = ps.iter().find(|p| !(0.0..=1.0).contains(*p)) {
+            return Err(NaiveDatetimeIterError::InvalidFraction { value });
+        }
+
+        let (start, end) = self.original_bounds();
+        Ok(ps.iter().map(|p| interpolate(start, end, *p)).collect())
+    }
+
+    /// Returns the datetime at fraction `f` of the way from `start` to `end`
+    ///
+    /// Uses the bounds given at construction, so `0.0` maps to `start` and
+    /// `1.0` to `end` for either step direction. `f` is clamped to `[0, 1]`
+    /// and a NaN is treated as `0.0`. The result is rounded to the nearest
+    /// nanosecond and need not lie on the step grid.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono_kit::iter::NaiveDatetimeIterator;
+    /// use chrono::{NaiveDateTime, Duration};
+    ///
+    /// let start = NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let end = NaiveDateTime::parse_from_str("2023-01-05 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    ///
+    /// let iter = NaiveDatetimeIterator::new(start, end, Duration::days(1)).unwrap();
+    /// assert_eq!(iter.at_fraction(0.25), start + Duration::days(1));
+    /// assert_eq!(iter.at_fraction(2.0), end);
+    /// ```
+    pub fn at_fraction(&self, f: f64) -> NaiveDateTime {
+        let f = if f.is_nan() { 0.0 } else { f.clamp(0.0, 1.0) };
+        let (start, end) = self.original_bounds();
+        interpolate(start, end, f)
+    }
+
+    /// Returns the signed duration from the `i`-th to the `j`-th grid point
+    ///
+    /// Positions count from the grid origin (`start`, or `end` for descending
+    /// steps) and include the clamped tail, regardless of iteration progress.
+    /// Uniform grids are computed arithmetically and calendar grids by
+    /// evaluating both points. Returns `None` if either position is past the
+    /// end of the grid.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono_kit::iter::NaiveDatetimeIterator;
+    /// use chrono::{NaiveDateTime, Duration};
+    ///
+    /// let start = NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let end = NaiveDateTime::parse_from_str("2023-01-01 10:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    ///
+    /// let iter = NaiveDatetimeIterator::new(start, end, Duration::hours(4)).unwrap();
+    /// assert_eq!(iter.step_between(0, 2), Some(Duration::hours(8)));
+    /// assert_eq!(iter.step_between(2, 3), Some(Duration::hours(2)));
+    /// assert_eq!(iter.step_between(0, 4), None);
+    /// ```
+    pub fn step_between(&self, i: usize, j: usize) -> Option<Duration> {
+        let (i, j) = (i as u128, j as u128);
+        if i >= self.total_len() || j >= self.total_len() {
+            return None;
+        }
+        Some(self.point_at(j) - self.point_at(i))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::iter::Step;
+
+    fn dt(s: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    #[test]
+    fn test_at_percentiles() {
+        let iter = NaiveDatetimeIterator::new(
+            dt("2023-01-01 00:00:00"),
+            dt("2023-01-01 10:00:00"),
+            Duration::hours(-3),
+        )
+        .unwrap();
+
+        assert_eq!(
+            iter.at_percentiles(&[0.0, 0.5, 1.0, 0.1, 0.9]).unwrap(),
+            vec![
+                dt("2023-01-01 00:00:00"),
+                dt("2023-01-01 05:00:00"),
+                dt("2023-01-01 10:00:00"),
+                dt("2023-01-01 01:00:00"),
+                dt("2023-01-01 09:00:00"),
+            ]
+        );
+        assert!(iter.at_percentiles(&[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_at_fraction() {
+        let iter = NaiveDatetimeIterator::new(
+            dt("2023-01-01 00:00:00"),
+            dt("2023-01-01 10:00:00"),
+            Duration::hours(3),
+        )
+        .unwrap();
+
+        assert_eq!(iter.at_fraction(0.0), dt("2023-01-01 00:00:00"));
+        assert_eq!(iter.at_fraction(0.25), dt("2023-01-01 02:30:00"));
+        assert_eq!(iter.at_fraction(1.0), dt("2023-01-01 10:00:00"));
+    }
+
+    #[test]
+    fn test_at_fraction_clamps() {
+        let iter = NaiveDatetimeIterator::new(
+            dt("2023-01-01 00:00:00"),
+            dt("2023-01-01 10:00:00"),
+            Duration::hours(-3),
+        )
+        .unwrap();
+
+        assert_eq!(iter.at_fraction(-0.5), dt("2023-01-01 00:00:00"));
+        assert_eq!(iter.at_fraction(1.5), dt("2023-01-01 10:00:00"));
+        assert_eq!(iter.at_fraction(f64::NAN), dt("2023-01-01 00:00:00"));
+    }
+
+    #[test]
+    fn test_step_between_uniform() {
+        let iter = NaiveDatetimeIterator::new(
+            dt("2023-01-01 00:00:00"),
+            dt("2023-01-01 10:00:00"),
+            Duration::hours(-3),
+        )
+        .unwrap();
+
+        assert_eq!(iter.step_between(0, 1), Some(Duration::hours(-3)));
+        assert_eq!(iter.step_between(1, 3), Some(Duration::hours(-6)));
+        assert_eq!(iter.step_between(3, 4), Some(Duration::hours(-1)));
+        assert_eq!(iter.step_between(4, 0), Some(Duration::hours(10)));
+        assert_eq!(iter.step_between(2, 2), Some(Duration::zero()));
+        assert_eq!(iter.step_between(5, 0), None);
+    }
+
+    #[test]
+    fn test_step_between_months() {
+        let mut iter = NaiveDatetimeIterator::with_step(
+            dt("2023-01-15 00:00:00"),
+            dt("2023-04-15 00:00:00"),
+            Step::months(1),
+        )
+        .unwrap();
+        iter.next();
+
+        assert_eq!(iter.step_between(0, 1), Some(Duration::days(31)));
+        assert_eq!(iter.step_between(1, 2), Some(Duration::days(28)));
+        assert_eq!(iter.step_between(0, 3), Some(Duration::days(90)));
+        assert_eq!(iter.step_between(0, 4), None);
+    }
+
+    #[test]
+    fn test_at_percentiles_rejects_out_of_range() {
+        let iter = NaiveDatetimeIterator::new(
+            dt("2023-01-01 00:00:00"),
+            dt("2023-01-01 10:00:00"),
+            Duration::hours(1),
+        )
+        .unwrap();
+
+        for bad in [-0.1, 1.5, f64::NAN] {
+            let result = iter.at_percentiles(&[0.5, bad]);
+            assert!(matches!(
+                result,
+                Err(NaiveDatetimeIterError::InvalidFraction { .. })
+            ));
+        }
+    }
+}
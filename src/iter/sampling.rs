@@ -0,0 +1,193 @@
+use super::naive_datetime_iter::NaiveDatetimeIterator;
+use super::naive_datetime_range_iter::NaiveDatetimeRangeIterator;
+use super::util::splitmix64;
+use chrono::NaiveDateTime;
+use std::collections::BTreeSet;
+
+impl NaiveDatetimeIterator {
+    /// Picks `k` distinct remaining datetimes at random, reproducibly for a given `seed`
+    ///
+    /// The grid positions are drawn directly from the known count of remaining
+    /// datetimes, so only the chosen points are ever computed and the work is
+    /// proportional to `k` rather than to the length of the grid. The sample is
+    /// returned in iteration order. If fewer than `k` datetimes remain, all of
+    /// them are returned.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono_kit::iter::NaiveDatetimeIterator;
+    /// use chrono::{NaiveDateTime, Duration};
+    ///
+    /// let start = NaiveDateTime::parse_from_str("2000-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let end = NaiveDateTime::parse_from_str("2100-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    ///
+    /// let iter = NaiveDatetimeIterator::new(start, end, Duration::seconds(1)).unwrap();
+    /// let sample = iter.sample_k(3, 42);
+    /// assert_eq!(sample.len(), 3);
+    /// assert!(sample.iter().all(|dt| (start..=end).contains(dt)));
+    /// ```
+    pub fn sample_k(self, k: usize, seed: u64) -> Vec<NaiveDateTime> {
+        let remaining = self.remaining_len();
+        let k = (k as u128).min(remaining);
+
+        // Floyd's algorithm draws k distinct offsets out of `remaining`
+        let mut state = seed;
+        let mut offsets = BTreeSet::new();
+        for j in remaining - k..remaining {
+            let random =
+                (u128::from(splitmix64(&mut state)) << 64) | u128::from(splitmix64(&mut state));
+            let t = random % (j + 1);
+            if !offsets.insert(t) {
+                offsets.insert(j);
+            }
+        }
+
+        offsets
+            .into_iter()
+            .filter_map(|offset| self.position_ahead(offset))
+            .map(|position| self.point_at(position))
+            .collect()
+    }
+}
+
+impl NaiveDatetimeRangeIterator {
+    /// Folds the samples falling in each range into an accumulated value
+    ///
+    /// `sorted` must be sorted ascending by datetime. Ranges are treated as
+    /// half-open `[start, end)`, except that the range touching the original
+    /// `end` also takes samples exactly at `end`, so each sample is folded into
+    /// at most one range. Every range starts from a clone of `init`.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono_kit::iter::NaiveDatetimeRangeIterator;
+    /// use chrono::{NaiveDateTime, Duration};
+    ///
+    /// let start = NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let end = NaiveDateTime::parse_from_str("2023-01-01 02:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let samples = [(start, 1.0), (start + Duration::minutes(30), 2.0), (end, 4.0)];
+    ///
+    /// let iter = NaiveDatetimeRangeIterator::new(start, end, Duration::hours(1)).unwrap();
+    /// let sums: Vec<_> = iter
+    ///     .fold_samples(&samples, 0.0, |acc, value| acc + value)
+    ///     .into_iter()
+    ///     .map(|(_, sum)| sum)
+    ///     .collect();
+    /// assert_eq!(sums, vec![3.0, 4.0]);
+    /// ```
+    pub fn fold_samples<T, A: Clone>(
+        self,
+        sorted: &[(NaiveDateTime, T)],
+        init: A,
+        f: impl Fn(A, &T) -> A,
+    ) -> Vec<((NaiveDateTime, NaiveDateTime), A)> {
+        let (_, last_end) = self.datetime_iter().original_bounds();
+
+        self.map(|(start, end)| {
+            let lo = sorted.partition_point(|(dt, _)| *dt < start);
+            let hi = if end == last_end {
+                sorted.partition_point(|(dt, _)| *dt <= end)
+            } else {
+                sorted.partition_point(|(dt, _)| *dt < end)
+            };
+            let acc = sorted[lo..hi]
+                .iter()
+                .fold(init.clone(), |acc, (_, value)| f(acc, value));
+            ((start, end), acc)
+        })
+        .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn dt(s: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    #[test]
+    fn test_sample_k_distinct_and_in_range() {
+        let start = dt("2023-01-01 00:00:00");
+        let end = dt("2023-01-01 23:59:59");
+        let iter = || NaiveDatetimeIterator::new(start, end, Duration::seconds(1)).unwrap();
+
+        let sample = iter().sample_k(50, 7);
+        assert_eq!(sample.len(), 50);
+        assert!(sample.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(sample.iter().all(|dt| (start..=end).contains(dt)));
+
+        assert_eq!(iter().sample_k(50, 7), sample);
+        assert_ne!(iter().sample_k(50, 8), sample);
+    }
+
+    #[test]
+    fn test_sample_k_respects_progress_and_direction() {
+        let start = dt("2023-01-01 00:00:00");
+        let end = dt("2023-01-01 05:00:00");
+
+        let mut iter = NaiveDatetimeIterator::new(start, end, Duration::hours(-1)).unwrap();
+        iter.nth(2);
+        let sample = iter.sample_k(10, 1);
+        assert_eq!(
+            sample,
+            vec![dt("2023-01-01 02:00:00"), dt("2023-01-01 01:00:00"), start]
+        );
+
+        let iter = NaiveDatetimeIterator::new(start, end, Duration::hours(1)).unwrap();
+        assert!(iter.sample_k(0, 1).is_empty());
+    }
+
+    #[test]
+    fn test_fold_samples_sums() {
+        let samples = [
+            (dt("2022-12-31 23:00:00"), 100.0),
+            (dt("2023-01-01 00:00:00"), 1.0),
+            (dt("2023-01-01 00:59:59"), 2.0),
+            (dt("2023-01-01 01:00:00"), 4.0),
+            (dt("2023-01-01 02:30:00"), 8.0),
+            (dt("2023-01-01 03:00:00"), 16.0),
+            (dt("2023-01-01 04:00:00"), 100.0),
+        ];
+        let iter = NaiveDatetimeRangeIterator::new(
+            dt("2023-01-01 00:00:00"),
+            dt("2023-01-01 03:00:00"),
+            Duration::hours(1),
+        )
+        .unwrap();
+
+        let sums = iter.fold_samples(&samples, 0.0, |acc, value| acc + value);
+        assert_eq!(
+            sums,
+            vec![
+                ((dt("2023-01-01 00:00:00"), dt("2023-01-01 01:00:00")), 3.0),
+                ((dt("2023-01-01 01:00:00"), dt("2023-01-01 02:00:00")), 4.0),
+                ((dt("2023-01-01 02:00:00"), dt("2023-01-01 03:00:00")), 24.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_fold_samples_descending() {
+        let samples = [
+            (dt("2023-01-01 00:30:00"), 1.0),
+            (dt("2023-01-01 01:00:00"), 2.0),
+            (dt("2023-01-01 02:00:00"), 4.0),
+        ];
+        let iter = NaiveDatetimeRangeIterator::new(
+            dt("2023-01-01 00:00:00"),
+            dt("2023-01-01 02:00:00"),
+            Duration::hours(-1),
+        )
+        .unwrap();
+
+        let counts: Vec<_> = iter
+            .fold_samples(&samples, 0usize, |acc, _| acc + 1)
+            .into_iter()
+            .map(|(_, count)| count)
+            .collect();
+        assert_eq!(counts, vec![2, 1]);
+    }
+}
@@ -0,0 +1,341 @@
+use super::naive_datetime_iter::{NaiveDatetimeIterError, NaiveDatetimeIterator};
+use super::step::{Step, parse_iso_duration};
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, Weekday};
+use std::collections::HashSet;
+use thiserror::Error;
+
+/// Error returned when a schedule string cannot be parsed
+#[derive(Debug, Error, PartialEq, Eq)]
+#[error("Invalid schedule {input:?} at byte {position}: {reason}")]
+pub struct ScheduleParseError {
+    /// The string that failed to parse
+    pub input: String,
+    /// Byte offset of the token that was rejected
+    pub position: usize,
+    /// Why the token was rejected
+    pub reason: &'static str,
+}
+
+/// A schedule parsed by [`parse_schedule`]
+#[derive(Debug, Clone)]
+pub struct Schedule {
+    /// The grid, built and validated when the schedule is parsed
+    points: NaiveDatetimeIterator,
+    skip_weekends: bool,
+    skip_holidays: bool,
+    times: Vec<NaiveTime>,
+}
+
+impl PartialEq for Schedule {
+    fn eq(&self, other: &Self) -> bool {
+        self.points.grid_eq(&other.points)
+            && self.skip_weekends == other.skip_weekends
+            && self.skip_holidays == other.skip_holidays
+            && self.times == other.times
+    }
+}
+
+impl Eq for Schedule {}
+
+impl Schedule {
+    /// Yields the datetimes of the schedule
+    ///
+    /// `holidays` is only consulted when the schedule says `skip holidays`.
+    /// With `at`, every date on the grid yields one datetime per listed time
+    /// of day instead of the grid point itself.
+    pub fn iter<'a>(
+        &'a self,
+        holidays: &'a HashSet<NaiveDate>,
+    ) -> impl Iterator<Item = NaiveDateTime> + 'a {
+        let points = self.points.clone();
+        let mut times = self.times.clone();
+        if !points.full_step().is_positive() {
+            times.reverse();
+        }
+        let mut last_date = None;
+
+        points
+            .filter(move |dt| {
+                let weekend = matches!(dt.weekday(), Weekday::Sat | Weekday::Sun);
+                let skipped = (self.skip_weekends && weekend)
+                    || (self.skip_holidays && holidays.contains(&dt.date()));
+                !skipped
+            })
+            .flat_map(move |dt| {
+                if times.is_empty() {
+                    return vec![dt];
+                }
+                if last_date.replace(dt.date()) == Some(dt.date()) {
+                    return Vec::new();
+                }
+                times.iter().map(|time| dt.date().and_time(*time)).collect()
+            })
+    }
+}
+
+/// Splits `s` on whitespace, keeping the byte offset of each token
+fn tokens(s: &str) -> Vec<(usize, &str)> {
+    let mut tokens = Vec::new();
+    let mut token_start = None;
+    for (i, c) in s.char_indices() {
+        match (c.is_whitespace(), token_start) {
+            (true, Some(start)) => {
+                tokens.push((start, &s[start..i]));
+                token_start = None;
+            }
+            (false, None) => token_start = Some(i),
+            _ => {}
+        }
+    }
+    if let Some(start) = token_start {
+        tokens.push((start, &s[start..]));
+    }
+    tokens
+}
+
+/// Parses a date, taken as midnight, or a `T`-separated datetime
+fn parse_bound(s: &str) -> Option<NaiveDateTime> {
+    NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S")
+        .ok()
+        .or_else(|| {
+            NaiveDate::parse_from_str(s, "%Y-%m-%d")
+                .ok()
+                .map(|date| date.and_time(NaiveTime::MIN))
+        })
+}
+
+/// Parses a compact duration such as `15m` or `-1d`, or an ISO 8601 duration
+fn parse_step(s: &str) -> Option<Step> {
+    if s.trim_start_matches('-').starts_with('P') {
+        return parse_iso_duration(s).ok();
+    }
+
+    let unit = s.chars().last()?;
+    let value: i64 = s[..s.len() - unit.len_utf8()].parse().ok()?;
+    let duration = match unit {
+        's' => Duration::try_seconds(value),
+        'm' => Duration::try_minutes(value),
+        'h' => Duration::try_hours(value),
+        'd' => Duration::try_days(value),
+        'w' => Duration::try_weeks(value),
+        _ => None,
+    };
+    duration.map(Step::from)
+}
+
+/// Parses a schedule such as `"2023-01-01..2023-02-01 by 1d skip weekends"`
+///
+/// A schedule is an inclusive `start..end` range followed by these clauses
+/// in any order:
+/// - `by <step>` (required): the step, either compact (`30s`, `15m`, `1h`,
+///   `1d`, `2w`, optionally negative to iterate descending) or an ISO 8601
+///   duration such as `P1M`
+/// - `skip weekends`: drops datetimes on Saturdays and Sundays
+/// - `skip holidays`: drops datetimes on the holidays passed to [`Schedule::iter`]
+/// - `at <time,time,...>`: yields the listed `HH:MM` or `HH:MM:SS` times on
+///   each date of the grid
+///
+/// Bounds are `YYYY-MM-DD` dates, taken as midnight, or
+/// `YYYY-MM-DDTHH:MM:SS` datetimes.
+///
+/// # Errors
+/// Returns `ScheduleParseError` with the byte offset of the offending token
+/// if the string does not follow this grammar, the step is zero, `start` is
+/// after `end`, or the step would put more datetimes on the grid than fit in
+/// a `usize`.
+///
+/// # Examples
+/// ```
+/// use chrono_kit::iter::parse_schedule;
+/// use chrono::{Datelike, Weekday};
+/// use std::collections::HashSet;
+///
+/// let schedule = parse_schedule("2023-01-01..2023-02-01 by 1d skip weekends").unwrap();
+/// let days: Vec<_> = schedule.iter(&HashSet::new()).collect();
+/// assert_eq!(days.len(), 23);
+/// assert!(days.iter().all(|dt| dt.weekday() != Weekday::Sat && dt.weekday() != Weekday::Sun));
+/// ```
+pub fn parse_schedule(s: &str) -> Result<Schedule, ScheduleParseError> {
+    let error = |position, reason| ScheduleParseError {
+        input: s.to_string(),
+        position,
+        reason,
+    };
+
+    let tokens = tokens(s);
+    let mut tokens = tokens.into_iter();
+    let (range_at, range) = tokens.next().ok_or_else(|| error(0, "empty schedule"))?;
+    let (start, end) = range
+        .split_once("..")
+        .ok_or_else(|| error(range_at, "expected a 'start..end' range"))?;
+    let end_at = range_at + start.len() + 2;
+    let start = parse_bound(start).ok_or_else(|| error(range_at, "invalid start"))?;
+    let end = parse_bound(end).ok_or_else(|| error(end_at, "invalid end"))?;
+    if start > end {
+        return Err(error(end_at, "end must not be before start"));
+    }
+
+    let mut step = None;
+    let mut skip_weekends = false;
+    let mut skip_holidays = false;
+    let mut times = Vec::new();
+    while let Some((keyword_at, keyword)) = tokens.next() {
+        let (value_at, value) = tokens
+            .next()
+            .ok_or_else(|| error(s.len(), "expected a value after the keyword"))?;
+        match keyword {
+            "by" if step.is_some() => return Err(error(keyword_at, "step given twice")),
+            "by" => {
+                let parsed = parse_step(value).ok_or_else(|| error(value_at, "invalid step"))?;
+                if parsed.is_zero() {
+                    return Err(error(value_at, "step cannot be zero"));
+                }
+                step = Some((value_at, parsed));
+            }
+            "skip" => match value {
+                "weekends" => skip_weekends = true,
+                "holidays" => skip_holidays = true,
+                _ => return Err(error(value_at, "expected 'weekends' or 'holidays'")),
+            },
+            "at" => {
+                let mut offset = value_at;
+                for time in value.split(',') {
+                    let parsed = NaiveTime::parse_from_str(time, "%H:%M:%S")
+                        .or_else(|_| NaiveTime::parse_from_str(time, "%H:%M"))
+                        .map_err(|_| error(offset, "invalid time of day"))?;
+                    times.push(parsed);
+                    offset += time.len() + 1;
+                }
+            }
+            _ => return Err(error(keyword_at, "expected 'by', 'skip', or 'at'")),
+        }
+    }
+
+    let (step_at, step) = step.ok_or_else(|| error(s.len(), "missing 'by <step>' clause"))?;
+    let points = NaiveDatetimeIterator::with_step(start, end, step).map_err(|e| {
+        let reason = match e {
+            NaiveDatetimeIterError::TooManyPoints { .. } => "step is too small for the range",
+            NaiveDatetimeIterError::MixedStepSigns => "step parts must have the same sign",
+            _ => "invalid step",
+        };
+        error(step_at, reason)
+    })?;
+    times.sort_unstable();
+    times.dedup();
+    Ok(Schedule {
+        points,
+        skip_weekends,
+        skip_holidays,
+        times,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dt(s: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    #[test]
+    fn test_parse_schedule_plain_step() {
+        let schedule = parse_schedule("2023-01-01..2023-01-01T12:00:00   by 5h").unwrap();
+        let points: Vec<_> = schedule.iter(&HashSet::new()).collect();
+        assert_eq!(
+            points,
+            vec![
+                dt("2023-01-01 00:00:00"),
+                dt("2023-01-01 05:00:00"),
+                dt("2023-01-01 10:00:00"),
+                dt("2023-01-01 12:00:00"),
+            ]
+        );
+
+        let schedule = parse_schedule("2023-01-01..2023-01-01T06:00:00 by -4h").unwrap();
+        let points: Vec<_> = schedule.iter(&HashSet::new()).collect();
+        assert_eq!(
+            points,
+            vec![
+                dt("2023-01-01 06:00:00"),
+                dt("2023-01-01 02:00:00"),
+                dt("2023-01-01 00:00:00"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_schedule_skip_weekends_and_holidays() {
+        let holidays = HashSet::from([NaiveDate::from_ymd_opt(2023, 1, 9).unwrap()]);
+
+        let schedule = parse_schedule("2023-01-06..2023-01-10 by 1d skip weekends").unwrap();
+        let days: Vec<_> = schedule.iter(&holidays).collect();
+        assert_eq!(
+            days,
+            vec![
+                dt("2023-01-06 00:00:00"),
+                dt("2023-01-09 00:00:00"),
+                dt("2023-01-10 00:00:00"),
+            ]
+        );
+
+        let schedule =
+            parse_schedule("2023-01-06..2023-01-10 skip holidays by -1d skip weekends").unwrap();
+        let days: Vec<_> = schedule.iter(&holidays).collect();
+        assert_eq!(
+            days,
+            vec![dt("2023-01-10 00:00:00"), dt("2023-01-06 00:00:00")]
+        );
+    }
+
+    #[test]
+    fn test_parse_schedule_at_times() {
+        let schedule = parse_schedule("2023-01-01..2023-01-02 by 6h at 17:00,09:30").unwrap();
+        let points: Vec<_> = schedule.iter(&HashSet::new()).collect();
+        assert_eq!(
+            points,
+            vec![
+                dt("2023-01-01 09:30:00"),
+                dt("2023-01-01 17:00:00"),
+                dt("2023-01-02 09:30:00"),
+                dt("2023-01-02 17:00:00"),
+            ]
+        );
+
+        let schedule = parse_schedule("2023-01-31..2023-03-31 by P1M at 12:00:00").unwrap();
+        let points: Vec<_> = schedule.iter(&HashSet::new()).collect();
+        assert_eq!(
+            points,
+            vec![
+                dt("2023-01-31 12:00:00"),
+                dt("2023-02-28 12:00:00"),
+                dt("2023-03-31 12:00:00"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_schedule_error_positions() {
+        let position = |s: &str| parse_schedule(s).unwrap_err().position;
+
+        assert_eq!(position(""), 0);
+        assert_eq!(position("2023-01-01 by 1d"), 0);
+        assert_eq!(position("2023-01-01..2023-13-01 by 1d"), 12);
+        assert_eq!(position("2023-01-02..2023-01-01 by 1d"), 12);
+        assert_eq!(position("2023-01-01..2023-01-02 by 1x"), 26);
+        assert_eq!(position("2023-01-01..2023-01-02 by 0h"), 26);
+        assert_eq!(position("2023-01-01..2023-01-02 by 1d skip mondays"), 34);
+        assert_eq!(position("2023-01-01..2023-01-02 by 1d at 09:00,25:00"), 38);
+        assert_eq!(position("2023-01-01..2023-01-02 every 1d"), 23);
+        assert_eq!(position("2023-01-01..2023-01-02 skip weekends"), 36);
+        assert_eq!(position("2023-01-01..2023-01-02 by"), 25);
+    }
+
+    #[test]
+    fn test_parse_schedule_rejects_oversized_grid() {
+        let input = "0001-01-01..9999-12-31 by PT0.000000001S";
+        let err = parse_schedule(input).unwrap_err();
+        assert_eq!(err.position, input.find("PT").unwrap());
+        assert_eq!(err.reason, "step is too small for the range");
+    }
+}
@@ -0,0 +1,146 @@
+use super::month_iter::NaiveDateIterError;
+use chrono::{Days, NaiveDate, NaiveDateTime, NaiveTime};
+
+/// Iterator over working shifts on each day of a date range
+///
+/// Every day from `start` to `end` inclusive yields one `(shift_start,
+/// shift_end)` range per shift, ordered by the shifts' start times. A shift
+/// whose end time is not after its start time wraps past midnight and ends
+/// on the following day, so `16:00–00:00` ends at midnight of the next day
+/// and a shift starting and ending at the same time lasts 24 hours.
+///
+/// # Examples
+/// ```
+/// use chrono_kit::iter::ShiftIterator;
+/// use chrono::{NaiveDate, NaiveTime};
+///
+/// let day = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+/// let night = (NaiveTime::from_hms_opt(22, 0, 0).unwrap(), NaiveTime::from_hms_opt(6, 0, 0).unwrap());
+///
+/// let mut iter = ShiftIterator::new(day, day, vec![night]).unwrap();
+/// assert_eq!(
+///     iter.next(),
+///     Some((day.and_time(night.0), day.succ_opt().unwrap().and_time(night.1)))
+/// );
+/// assert_eq!(iter.next(), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ShiftIterator {
+    current: Option<NaiveDate>,
+    end: NaiveDate,
+    shifts: Vec<(NaiveTime, NaiveTime)>,
+    index: usize,
+}
+
+impl ShiftIterator {
+    /// Creates a new ShiftIterator
+    ///
+    /// # Arguments
+    /// * `start` - The first day with shifts (inclusive)
+    /// * `end` - The last day on which a shift may start (inclusive)
+    /// * `shifts` - The `(start, end)` times of day of each shift
+    ///
+    /// # Errors
+    /// Returns `NaiveDateIterError::InvalidRange` if `start` is after `end`.
+    pub fn new(
+        start: NaiveDate,
+        end: NaiveDate,
+        mut shifts: Vec<(NaiveTime, NaiveTime)>,
+    ) -> Result<Self, NaiveDateIterError> {
+        if start > end {
+            return Err(NaiveDateIterError::InvalidRange { start, end });
+        }
+        shifts.sort_unstable();
+        Ok(ShiftIterator {
+            current: Some(start).filter(|_| !shifts.is_empty()),
+            end,
+            shifts,
+            index: 0,
+        })
+    }
+}
+
+impl Iterator for ShiftIterator {
+    type Item = (NaiveDateTime, NaiveDateTime);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let day = self.current?;
+        let (shift_start, shift_end) = self.shifts[self.index];
+        let end_day = if shift_end <= shift_start {
+            day.checked_add_days(Days::new(1))?
+        } else {
+            day
+        };
+
+        self.index += 1;
+        if self.index == self.shifts.len() {
+            self.index = 0;
+            self.current = day.succ_opt().filter(|next| *next <= self.end);
+        }
+        Some((day.and_time(shift_start), end_day.and_time(shift_end)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dt(s: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    fn time(h: u32, m: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(h, m, 0).unwrap()
+    }
+
+    #[test]
+    fn test_two_shifts_over_two_days() {
+        let start = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2023, 1, 2).unwrap();
+        let shifts = vec![(time(16, 0), time(0, 0)), (time(8, 0), time(16, 0))];
+
+        let ranges: Vec<_> = ShiftIterator::new(start, end, shifts).unwrap().collect();
+        assert_eq!(
+            ranges,
+            vec![
+                (dt("2023-01-01 08:00:00"), dt("2023-01-01 16:00:00")),
+                (dt("2023-01-01 16:00:00"), dt("2023-01-02 00:00:00")),
+                (dt("2023-01-02 08:00:00"), dt("2023-01-02 16:00:00")),
+                (dt("2023-01-02 16:00:00"), dt("2023-01-03 00:00:00")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_overnight_and_full_day_shifts() {
+        let day = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+
+        let ranges: Vec<_> = ShiftIterator::new(day, day, vec![(time(22, 30), time(6, 30))])
+            .unwrap()
+            .collect();
+        assert_eq!(
+            ranges,
+            vec![(dt("2023-01-01 22:30:00"), dt("2023-01-02 06:30:00"))]
+        );
+
+        let ranges: Vec<_> = ShiftIterator::new(day, day, vec![(time(7, 0), time(7, 0))])
+            .unwrap()
+            .collect();
+        assert_eq!(
+            ranges,
+            vec![(dt("2023-01-01 07:00:00"), dt("2023-01-02 07:00:00"))]
+        );
+    }
+
+    #[test]
+    fn test_no_shifts_and_invalid_range() {
+        let start = NaiveDate::from_ymd_opt(2023, 1, 1).unwrap();
+        let end = NaiveDate::from_ymd_opt(2023, 1, 5).unwrap();
+
+        assert_eq!(ShiftIterator::new(start, end, vec![]).unwrap().next(), None);
+        assert!(matches!(
+            ShiftIterator::new(end, start, vec![(time(8, 0), time(16, 0))]),
+            Err(NaiveDateIterError::InvalidRange { .. })
+        ));
+    }
+}
@@ -0,0 +1,202 @@
+use super::naive_datetime_iter::NaiveDatetimeIterError;
+use chrono::{Duration, NaiveDateTime};
+
+/// Iterator that yields fixed-length ranges advancing by a stride
+///
+/// Ranges are `(s, s + window)` for `s = start, start + stride, ...`, so they
+/// overlap when `stride` is shorter than `window` and leave gaps when it is
+/// longer. Only windows starting before `end` are yielded.
+///
+/// By default the first window running past `end` is clamped to end there
+/// and is the last one yielded, since any later window would lie within it.
+/// With `stride == window` this matches
+/// [`NaiveDatetimeRangeIterator`](crate::iter::NaiveDatetimeRangeIterator)
+/// with the same step. Use [`without_clamping`](Self::without_clamping) to
+/// drop partial windows instead.
+///
+/// # Examples
+/// ```
+/// use chrono_kit::iter::SlidingWindowIterator;
+/// use chrono::{NaiveDateTime, Duration};
+///
+/// let start = NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+/// let end = NaiveDateTime::parse_from_str("2023-01-01 01:30:00", "%Y-%m-%d %H:%M:%S").unwrap();
+/// let (window, stride) = (Duration::hours(1), Duration::minutes(15));
+///
+/// let mut iter = SlidingWindowIterator::new(start, end, window, stride).unwrap();
+/// assert_eq!(iter.next(), Some((start, start + window)));
+/// assert_eq!(iter.next(), Some((start + stride, start + stride + window)));
+/// assert_eq!(iter.count(), 2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SlidingWindowIterator {
+    next: Option<NaiveDateTime>,
+    end: NaiveDateTime,
+    window: Duration,
+    stride: Duration,
+    clamp: bool,
+}
+
+impl SlidingWindowIterator {
+    /// Creates a new SlidingWindowIterator
+    ///
+    /// The signs of `window` and `stride` are ignored.
+    ///
+    /// # Arguments
+    /// * `start` - The start of the first window
+    /// * `end` - The datetime no window extends past
+    /// * `window` - The length of each window
+    /// * `stride` - The distance between the starts of consecutive windows
+    ///
+    /// # Errors
+    /// Returns `DatetimeIterError` if:
+    /// - `window` or `stride` is zero
+    /// - `start` is after `end`
+    pub fn new(
+        start: NaiveDateTime,
+        end: NaiveDateTime,
+        window: Duration,
+        stride: Duration,
+    ) -> Result<Self, NaiveDatetimeIterError> {
+        if window.is_zero() || stride.is_zero() {
+            return Err(NaiveDatetimeIterError::ZeroStep);
+        }
+        if start > end {
+            return Err(NaiveDatetimeIterError::InvalidRange { start, end });
+        }
+        Ok(SlidingWindowIterator {
+            next: Some(start),
+            end,
+            window: window.abs(),
+            stride: stride.abs(),
+            clamp: true,
+        })
+    }
+
+    /// Disables clamping so that only windows lying entirely before `end` are yielded
+    pub fn without_clamping(mut self) -> Self {
+        self.clamp = false;
+        self
+    }
+}
+
+impl Iterator for SlidingWindowIterator {
+    type Item = (NaiveDateTime, NaiveDateTime);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = self.next.take().filter(|start| *start < self.end)?;
+
+        match start.checked_add_signed(self.window) {
+            Some(end) if end <= self.end => {
+                self.next = start.checked_add_signed(self.stride);
+                Some((start, end))
+            }
+            _ if self.clamp => Some((start, self.end)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::iter::NaiveDatetimeRangeIterator;
+
+    fn dt(s: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    #[test]
+    fn test_overlapping_windows() {
+        let iter = SlidingWindowIterator::new(
+            dt("2023-01-01 00:00:00"),
+            dt("2023-01-01 01:40:00"),
+            Duration::hours(1),
+            Duration::minutes(15),
+        )
+        .unwrap();
+
+        let windows: Vec<_> = iter.collect();
+        assert_eq!(
+            windows,
+            vec![
+                (dt("2023-01-01 00:00:00"), dt("2023-01-01 01:00:00")),
+                (dt("2023-01-01 00:15:00"), dt("2023-01-01 01:15:00")),
+                (dt("2023-01-01 00:30:00"), dt("2023-01-01 01:30:00")),
+                (dt("2023-01-01 00:45:00"), dt("2023-01-01 01:40:00")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_without_clamping_drops_partial_window() {
+        let iter = SlidingWindowIterator::new(
+            dt("2023-01-01 00:00:00"),
+            dt("2023-01-01 01:40:00"),
+            Duration::hours(1),
+            Duration::minutes(15),
+        )
+        .unwrap()
+        .without_clamping();
+
+        let windows: Vec<_> = iter.collect();
+        assert_eq!(windows.len(), 3);
+        assert_eq!(windows[2].1, dt("2023-01-01 01:30:00"));
+    }
+
+    #[test]
+    fn test_stride_equal_to_window_matches_range_iterator() {
+        let start = dt("2023-01-01 00:00:00");
+        let step = Duration::hours(2);
+
+        for end in [dt("2023-01-01 06:00:00"), dt("2023-01-01 07:00:00"), start] {
+            let windows: Vec<_> = SlidingWindowIterator::new(start, end, step, step)
+                .unwrap()
+                .collect();
+            let ranges: Vec<_> = NaiveDatetimeRangeIterator::new(start, end, step)
+                .unwrap()
+                .collect();
+            assert_eq!(windows, ranges);
+        }
+    }
+
+    #[test]
+    fn test_stride_longer_than_window() {
+        let iter = SlidingWindowIterator::new(
+            dt("2023-01-01 00:00:00"),
+            dt("2023-01-01 03:00:00"),
+            Duration::minutes(30),
+            Duration::hours(-1),
+        )
+        .unwrap();
+
+        let starts: Vec<_> = iter.map(|(start, _)| start).collect();
+        assert_eq!(
+            starts,
+            vec![
+                dt("2023-01-01 00:00:00"),
+                dt("2023-01-01 01:00:00"),
+                dt("2023-01-01 02:00:00"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let start = dt("2023-01-01 00:00:00");
+        let end = dt("2023-01-01 01:00:00");
+
+        assert!(matches!(
+            SlidingWindowIterator::new(start, end, Duration::zero(), Duration::minutes(1)),
+            Err(NaiveDatetimeIterError::ZeroStep)
+        ));
+        assert!(matches!(
+            SlidingWindowIterator::new(start, end, Duration::minutes(1), Duration::zero()),
+            Err(NaiveDatetimeIterError::ZeroStep)
+        ));
+        assert!(matches!(
+            SlidingWindowIterator::new(end, start, Duration::minutes(1), Duration::minutes(1)),
+            Err(NaiveDatetimeIterError::InvalidRange { .. })
+        ));
+    }
+}
@@ -0,0 +1,342 @@
+use super::util::{duration_to_nanos, nanos_to_duration};
+use chrono::{Duration, Months, NaiveDateTime};
+use thiserror::Error;
+
+/// Error returned when an ISO 8601 duration cannot be parsed
+#[derive(Debug, Error, PartialEq, Eq)]
+#[error("Invalid ISO 8601 duration {input:?}: {reason}")]
+pub struct ParseStepError {
+    /// The string that failed to parse
+    pub input: String,
+    /// Why the string was rejected
+    pub reason: &'static str,
+}
+
+/// A step combining calendar months with an exact duration
+///
+/// Years and months are applied with calendar arithmetic, clamping to the
+/// last day of shorter months, while the `duration` part is exact elapsed
+/// time. Both parts must share the same sign; a negative step iterates in
+/// descending order.
+///
+/// # Examples
+/// ```
+/// use chrono_kit::iter::Step;
+/// use chrono::Duration;
+///
+/// let step = Step::months(1) + Duration::days(1);
+/// assert_eq!(step.months, 1);
+/// assert_eq!(step.duration, Duration::days(1));
+/// assert!(step.is_calendar());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Step {
+    /// Number of calendar months, years counting as 12 months each
+    pub months: i32,
+    /// Exact elapsed time applied after the months
+    pub duration: Duration,
+}
+
+impl Step {
+    /// Creates a step of `n` calendar months
+    pub fn months(n: i32) -> Self {
+        Step {
+            months: n,
+            duration: Duration::zero(),
+        }
+    }
+
+    /// Creates a step of `n` calendar years
+    ///
+    /// # Panics
+    /// Panics if `n * 12` overflows an `i32`.
+    pub fn years(n: i32) -> Self {
+        Step::months(n.checked_mul(12).expect("year step overflows months"))
+    }
+
+    /// Returns `true` if the step has no calendar or duration component
+    pub fn is_zero(&self) -> bool {
+        self.months == 0 && self.duration.is_zero()
+    }
+
+    /// Returns `true` if the step has a calendar month component
+    pub fn is_calendar(&self) -> bool {
+        self.months != 0
+    }
+
+    /// Returns `true` if the step moves forward in time
+    pub fn is_positive(&self) -> bool {
+        self.months > 0 || self.duration > Duration::zero()
+    }
+
+    /// Returns `true` if the month and duration parts point in opposite directions
+    pub(crate) fn has_mixed_signs(&self) -> bool {
+        (self.months > 0 && self.duration < Duration::zero())
+            || (self.months < 0 && self.duration > Duration::zero())
+    }
+
+    /// Applies the step `times` times to `dt`, returning `None` on overflow
+    ///
+    /// The months are applied in one go, so `Jan 31 + 2 * (1 month)` lands on
+    /// `Mar 31` rather than drifting through `Feb 28`.
+    pub(crate) fn apply(&self, dt: NaiveDateTime, times: u128) -> Option<NaiveDateTime> {
+        let months =
+            u32::try_from((self.months.unsigned_abs() as u128).checked_mul(times)?).ok()?;
+        let dt = if self.months >= 0 {
+            dt.checked_add_months(Months::new(months))?
+        } else {
+            dt.checked_sub_months(Months::new(months))?
+        };
+
+        let times = i128::try_from(times).ok()?;
+        let offset = nanos_to_duration(duration_to_nanos(self.duration).checked_mul(times)?)?;
+        dt.checked_add_signed(offset)
+    }
+}
+
+impl From<Duration> for Step {
+    fn from(duration: Duration) -> Self {
+        Step {
+            months: 0,
+            duration,
+        }
+    }
+}
+
+impl std::ops::Add<Duration> for Step {
+    type Output = Step;
+
+    fn add(self, duration: Duration) -> Step {
+        Step {
+            months: self.months,
+            duration: self.duration + duration,
+        }
+    }
+}
+
+impl std::ops::Neg for Step {
+    type Output = Step;
+
+    fn neg(self) -> Step {
+        Step {
+            months: -self.months,
+            duration: -self.duration,
+        }
+    }
+}
+
+/// Parses an ISO 8601 duration such as `P1Y2M`, `P3W`, or `PT1H30M` into a [`Step`]
+///
+/// Years and months become the calendar part of the step, while weeks, days,
+/// hours, minutes, and seconds become its exact duration, with days counted
+/// as 24 hours. Only the seconds may have a fractional part, using `.` or `,`.
+/// A leading `-` negates the whole step.
+///
+/// # Errors
+/// Returns `ParseStepError` if the string is not a valid ISO 8601 duration,
+/// has no components, or overflows.
+///
+/// # Examples
+/// ```
+/// use chrono_kit::iter::{parse_iso_duration, Step};
+/// use chrono::Duration;
+///
+/// assert_eq!(parse_iso_duration("PT1H30M").unwrap(), Step::from(Duration::minutes(90)));
+/// assert_eq!(parse_iso_duration("P1Y2M").unwrap(), Step::months(14));
+/// ```
+pub fn parse_iso_duration(s: &str) -> Result<Step, ParseStepError> {
+    let error = |reason| ParseStepError {
+        input: s.to_string(),
+        reason,
+    };
+
+    let (negative, rest) = match s.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, s),
+    };
+    let rest = rest
+        .strip_prefix('P')
+        .ok_or_else(|| error("must start with 'P'"))?;
+    let (date_part, time_part) = match rest.split_once('T') {
+        Some((_, "")) => return Err(error("'T' must be followed by a time component")),
+        Some((date, time)) => (date, Some(time)),
+        None => (rest, None),
+    };
+
+    let mut months: i64 = 0;
+    let mut nanos: i128 = 0;
+    let mut components = 0;
+
+    for (value, unit) in components_of(date_part).map_err(error)? {
+        let value = whole(value).ok_or_else(|| error("only seconds may be fractional"))?;
+        match unit {
+            'Y' | 'M' => {
+                let value = if unit == 'Y' {
+                    value.checked_mul(12)
+                } else {
+                    Some(value)
+                };
+                months = value
+                    .and_then(|value| months.checked_add(value))
+                    .ok_or_else(|| error("overflow"))?;
+            }
+            'W' => nanos += value as i128 * 7 * 86_400 * 1_000_000_000,
+            'D' => nanos += value as i128 * 86_400 * 1_000_000_000,
+            _ => return Err(error("unexpected date designator")),
+        }
+        components += 1;
+    }
+    if let Some(time_part) = time_part {
+        for (value, unit) in components_of(time_part).map_err(error)? {
+            let scale: i128 = match unit {
+                'H' => 3_600 * 1_000_000_000,
+                'M' => 60 * 1_000_000_000,
+                'S' => {
+                    nanos += fractional_seconds(value).ok_or_else(|| error("invalid seconds"))?;
+                    components += 1;
+                    continue;
+                }
+                _ => return Err(error("unexpected time designator")),
+            };
+            let value = whole(value).ok_or_else(|| error("only seconds may be fractional"))?;
+            nanos += value as i128 * scale;
+            components += 1;
+        }
+    }
+    if components == 0 {
+        return Err(error("no duration components"));
+    }
+
+    let months = i32::try_from(months).map_err(|_| error("overflow"))?;
+    let duration = nanos_to_duration(nanos).ok_or_else(|| error("overflow"))?;
+    let step = Step { months, duration };
+    Ok(if negative { -step } else { step })
+}
+
+/// Splits `s` into `(number, designator)` pairs, rejecting repeated designators
+fn components_of(s: &str) -> Result<Vec<(&str, char)>, &'static str> {
+    let mut components = Vec::new();
+    let mut rest = s;
+    while !rest.is_empty() {
+        let end = rest
+            .find(|c: char| c.is_ascii_alphabetic())
+            .ok_or("number without designator")?;
+        let (value, tail) = rest.split_at(end);
+        if value.is_empty() {
+            return Err("designator without number");
+        }
+        let unit = tail.chars().next().unwrap();
+        if components.iter().any(|(_, seen)| *seen == unit) {
+            return Err("repeated designator");
+        }
+        components.push((value, unit));
+        rest = &tail[1..];
+    }
+    Ok(components)
+}
+
+/// Parses a whole, non-negative component value
+fn whole(value: &str) -> Option<i64> {
+    if !value.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    value.parse().ok()
+}
+
+/// Parses a possibly fractional number of seconds into nanoseconds
+fn fractional_seconds(value: &str) -> Option<i128> {
+    let (secs, frac) = match value.split_once(['.', ',']) {
+        Some((secs, frac)) => (secs, frac),
+        None => (value, ""),
+    };
+    if frac.len() > 9 || !frac.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let secs = whole(secs)? as i128;
+    let frac = format!("{frac:0<9}").parse::<i128>().ok()?;
+    Some(secs * 1_000_000_000 + frac)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_time_components() {
+        assert_eq!(
+            parse_iso_duration("PT1H").unwrap(),
+            Step::from(Duration::hours(1))
+        );
+        assert_eq!(
+            parse_iso_duration("PT1H30M").unwrap(),
+            Step::from(Duration::minutes(90))
+        );
+        assert_eq!(
+            parse_iso_duration("PT0.5S").unwrap(),
+            Step::from(Duration::milliseconds(500))
+        );
+        assert_eq!(
+            parse_iso_duration("P1DT12H").unwrap(),
+            Step::from(Duration::hours(36))
+        );
+        assert_eq!(
+            parse_iso_duration("P2W").unwrap(),
+            Step::from(Duration::days(14))
+        );
+    }
+
+    #[test]
+    fn test_parse_calendar_components() {
+        assert_eq!(parse_iso_duration("P1M").unwrap(), Step::months(1));
+        assert_eq!(parse_iso_duration("P1Y").unwrap(), Step::years(1));
+        assert_eq!(parse_iso_duration("P1Y2M").unwrap(), Step::months(14));
+        assert_eq!(
+            parse_iso_duration("P1MT1M").unwrap(),
+            Step::months(1) + Duration::minutes(1)
+        );
+        assert_eq!(parse_iso_duration("-P1M").unwrap(), Step::months(-1));
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        for input in [
+            "", "P", "PT", "1H", "PT1", "P1H", "PTM", "P1.5D", "P1M1M", "PT1,5H", "P-1D",
+        ] {
+            assert!(
+                parse_iso_duration(input).is_err(),
+                "{input} should not parse"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_month_overflow() {
+        for input in [
+            "P768614336404564650Y9223372036854775807M",
+            "P9223372036854775807Y",
+            "P2147483648M",
+        ] {
+            let err = parse_iso_duration(input).unwrap_err();
+            assert_eq!(err.reason, "overflow", "{input}");
+        }
+    }
+
+    #[test]
+    fn test_apply_clamps_without_drift() {
+        let jan31 =
+            NaiveDateTime::parse_from_str("2023-01-31 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let step = Step::months(1);
+        assert_eq!(
+            step.apply(jan31, 1).unwrap().to_string(),
+            "2023-02-28 00:00:00"
+        );
+        assert_eq!(
+            step.apply(jan31, 2).unwrap().to_string(),
+            "2023-03-31 00:00:00"
+        );
+        assert_eq!(
+            (-step).apply(jan31, 1).unwrap().to_string(),
+            "2022-12-31 00:00:00"
+        );
+    }
+}
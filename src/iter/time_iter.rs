@@ -0,0 +1,308 @@
+use chrono::{Duration, NaiveTime};
+use thiserror::Error;
+
+/// Errors that can occur when creating a time-of-day iterator
+#[derive(Debug, Error)]
+pub enum NaiveTimeIterError {
+    /// Returned when the step is zero
+    #[error("Step cannot be zero")]
+    ZeroStep,
+    /// Returned when the step is negative
+    #[error("Invalid step: {step} must be positive")]
+    NegativeStep { step: Duration },
+    /// Returned when start is after end and wrapping past midnight is disabled
+    #[error("Invalid range: start {start} must be before end {end} when not wrapping")]
+    InvalidRange { start: NaiveTime, end: NaiveTime },
+}
+
+/// How a [`NaiveTimeIterator`] treats an end time earlier than its start time
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MidnightMode {
+    /// Never cross midnight; an end before the start is an error
+    Stop,
+    /// Cross midnight at most once, so `23:00 → 01:00` spans two hours
+    Wrap,
+}
+
+/// An iterator that yields clock times between a start and end time (inclusive)
+///
+/// Times are yielded from `start` forward in increments of `step` until `end`
+/// is reached. As with [`NaiveDatetimeIterator`](crate::iter::NaiveDatetimeIterator),
+/// `end` is always yielded last even if it is not a whole number of steps away.
+///
+/// The distance from `start` to `end` is always less than 24 hours, so the
+/// iterator never loops forever:
+/// - With [`MidnightMode::Stop`], `end` must not be before `start` and the
+///   times stay within a single day.
+/// - With [`MidnightMode::Wrap`], an `end` before `start` is reached by
+///   crossing midnight once. An `end` equal to `start` yields a single time,
+///   never a full day.
+///
+/// # Examples
+///
+/// Within a day:
+/// ```
+/// use chrono_kit::iter::{MidnightMode, NaiveTimeIterator};
+/// use chrono::{Duration, NaiveTime};
+///
+/// let start = NaiveTime::from_hms_opt(9, 0, 0).unwrap();
+/// let end = NaiveTime::from_hms_opt(17, 0, 0).unwrap();
+///
+/// let iter = NaiveTimeIterator::new(start, end, Duration::hours(1), MidnightMode::Stop).unwrap();
+/// assert_eq!(iter.count(), 9);
+/// ```
+///
+/// Across midnight:
+/// ```
+/// use chrono_kit::iter::{MidnightMode, NaiveTimeIterator};
+/// use chrono::{Duration, NaiveTime};
+///
+/// let start = NaiveTime::from_hms_opt(23, 0, 0).unwrap();
+/// let end = NaiveTime::from_hms_opt(1, 0, 0).unwrap();
+///
+/// let mut iter = NaiveTimeIterator::new(start, end, Duration::hours(1), MidnightMode::Wrap).unwrap();
+/// assert_eq!(iter.next(), Some(start));
+/// assert_eq!(iter.next(), Some(NaiveTime::MIN));
+/// assert_eq!(iter.next(), Some(end));
+/// assert_eq!(iter.next(), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct NaiveTimeIterator {
+    start: NaiveTime,
+    span: Duration,
+    step: Duration,
+    offset: Option<Duration>,
+}
+
+impl NaiveTimeIterator {
+    /// Creates a new NaiveTimeIterator
+    ///
+    /// # Arguments
+    /// * `start` - The starting time (inclusive)
+    /// * `end` - The ending time (inclusive)
+    /// * `step` - The positive duration between each step
+    /// * `mode` - Whether an `end` before `start` wraps past midnight
+    ///
+    /// # Errors
+    /// Returns `NaiveTimeIterError` if:
+    /// - `step` is zero or negative
+    /// - `start` is after `end` and `mode` is [`MidnightMode::Stop`]
+    pub fn new(
+        start: NaiveTime,
+        end: NaiveTime,
+        step: Duration,
+        mode: MidnightMode,
+    ) -> Result<Self, NaiveTimeIterError> {
+        if step.is_zero() {
+            return Err(NaiveTimeIterError::ZeroStep);
+        }
+        if step < Duration::zero() {
+            return Err(NaiveTimeIterError::NegativeStep { step });
+        }
+        let mut span = end - start;
+        if span < Duration::zero() {
+            match mode {
+                MidnightMode::Stop => return Err(NaiveTimeIterError::InvalidRange { start, end }),
+                MidnightMode::Wrap => span += Duration::days(1),
+            }
+        }
+        Ok(NaiveTimeIterator {
+            start,
+            span,
+            step,
+            offset: Some(Duration::zero()),
+        })
+    }
+
+    fn remaining_len(&self) -> usize {
+        let Some(offset) = self.offset else {
+            return 0;
+        };
+        // Past the last whole step only the clamped `end` is left
+        if offset >= self.span {
+            return 1;
+        }
+        // Both durations are below 24 hours, so their nanoseconds fit in an i64
+        let left = (self.span - offset).num_nanoseconds().unwrap() as u64;
+        let step = self.step.num_nanoseconds().unwrap_or(i64::MAX) as u64;
+        (left.div_ceil(step) + 1) as usize
+    }
+}
+
+impl Iterator for NaiveTimeIterator {
+    type Item = NaiveTime;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let offset = self.offset?;
+        let offset = if offset >= self.span {
+            self.offset = None;
+            self.span
+        } else {
+            self.offset = offset.checked_add(&self.step);
+            offset
+        };
+        Some(self.start.overflowing_add_signed(offset).0)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.remaining_len();
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for NaiveTimeIterator {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn time(h: u32, m: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(h, m, 0).unwrap()
+    }
+
+    #[test]
+    fn test_business_hours() {
+        let mut iter = NaiveTimeIterator::new(
+            time(9, 0),
+            time(17, 0),
+            Duration::minutes(15),
+            MidnightMode::Stop,
+        )
+        .unwrap();
+        assert_eq!(iter.len(), 33);
+        assert_eq!(iter.next(), Some(time(9, 0)));
+        assert_eq!(iter.next(), Some(time(9, 15)));
+        assert_eq!(iter.len(), 31);
+        assert_eq!(iter.last(), Some(time(17, 0)));
+    }
+
+    #[test]
+    fn test_wrap_past_midnight() {
+        let times: Vec<_> = NaiveTimeIterator::new(
+            time(23, 0),
+            time(1, 0),
+            Duration::minutes(15),
+            MidnightMode::Wrap,
+        )
+        .unwrap()
+        .collect();
+        assert_eq!(times.len(), 9);
+        assert_eq!(times[0], time(23, 0));
+        assert_eq!(times[3], time(23, 45));
+        assert_eq!(times[4], time(0, 0));
+        assert_eq!(times[8], time(1, 0));
+    }
+
+    #[test]
+    fn test_wrap_without_crossing_midnight() {
+        let iter = NaiveTimeIterator::new(
+            time(9, 0),
+            time(17, 0),
+            Duration::hours(1),
+            MidnightMode::Wrap,
+        )
+        .unwrap();
+        assert_eq!(iter.count(), 9);
+    }
+
+    #[test]
+    fn test_non_integer_period() {
+        let times: Vec<_> = NaiveTimeIterator::new(
+            time(22, 0),
+            time(0, 30),
+            Duration::hours(1),
+            MidnightMode::Wrap,
+        )
+        .unwrap()
+        .collect();
+        assert_eq!(
+            times,
+            vec![time(22, 0), time(23, 0), time(0, 0), time(0, 30)]
+        );
+    }
+
+    #[test]
+    fn test_equal_bounds_yield_once() {
+        for mode in [MidnightMode::Stop, MidnightMode::Wrap] {
+            let times: Vec<_> =
+                NaiveTimeIterator::new(time(12, 0), time(12, 0), Duration::hours(1), mode)
+                    .unwrap()
+                    .collect();
+            assert_eq!(times, vec![time(12, 0)]);
+        }
+    }
+
+    #[test]
+    fn test_step_longer_than_a_day() {
+        let times: Vec<_> = NaiveTimeIterator::new(
+            time(23, 0),
+            time(1, 0),
+            Duration::days(3),
+            MidnightMode::Wrap,
+        )
+        .unwrap()
+        .collect();
+        assert_eq!(times, vec![time(23, 0), time(1, 0)]);
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(matches!(
+            NaiveTimeIterator::new(
+                time(9, 0),
+                time(17, 0),
+                Duration::zero(),
+                MidnightMode::Stop
+            ),
+            Err(NaiveTimeIterError::ZeroStep)
+        ));
+        assert!(matches!(
+            NaiveTimeIterator::new(
+                time(9, 0),
+                time(17, 0),
+                Duration::hours(-1),
+                MidnightMode::Wrap
+            ),
+            Err(NaiveTimeIterError::NegativeStep { .. })
+        ));
+        assert!(matches!(
+            NaiveTimeIterator::new(
+                time(23, 0),
+                time(1, 0),
+                Duration::hours(1),
+                MidnightMode::Stop
+            ),
+            Err(NaiveTimeIterError::InvalidRange { .. })
+        ));
+    }
+
+    #[test]
+    fn test_len_matches_drain_after_partial_iteration() {
+        let cases = [
+            (
+                time(22, 0),
+                time(0, 30),
+                Duration::hours(1),
+                MidnightMode::Wrap,
+            ),
+            (
+                time(9, 0),
+                time(17, 0),
+                Duration::days(3),
+                MidnightMode::Stop,
+            ),
+        ];
+
+        for (start, end, step, mode) in cases {
+            let total = NaiveTimeIterator::new(start, end, step, mode)
+                .unwrap()
+                .count();
+            for taken in 0..=total {
+                let mut iter = NaiveTimeIterator::new(start, end, step, mode).unwrap();
+                iter.by_ref().take(taken).for_each(drop);
+                assert_eq!(iter.len(), total - taken);
+                assert_eq!(iter.count(), total - taken);
+            }
+        }
+    }
+}
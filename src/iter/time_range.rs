@@ -0,0 +1,263 @@
+use super::naive_datetime_iter::NaiveDatetimeIterator;
+use super::naive_datetime_range_iter::NaiveDatetimeRangeIterator;
+use chrono::{Duration, NaiveDateTime};
+
+/// A half-open datetime range `[start, end)`
+///
+/// # Examples
+/// ```
+/// use chrono_kit::iter::TimeRange;
+/// use chrono::{NaiveDateTime, Duration};
+///
+/// let start = NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+/// let range = TimeRange::new(start, start + Duration::hours(1));
+/// assert!(range.contains(start));
+/// assert!(!range.contains(range.end));
+/// assert_eq!(range.duration(), Duration::hours(1));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TimeRange {
+    /// The first instant of the range (inclusive)
+    pub start: NaiveDateTime,
+    /// The instant the range stops at (exclusive)
+    pub end: NaiveDateTime,
+}
+
+impl TimeRange {
+    /// Creates a new TimeRange
+    pub fn new(start: NaiveDateTime, end: NaiveDateTime) -> Self {
+        TimeRange { start, end }
+    }
+
+    /// Returns the length of the range
+    pub fn duration(&self) -> Duration {
+        self.end - self.start
+    }
+
+    /// Returns `true` if `dt` lies within `[start, end)`
+    pub fn contains(&self, dt: NaiveDateTime) -> bool {
+        self.start <= dt && dt < self.end
+    }
+
+    /// Returns `true` if the two ranges share at least one instant
+    ///
+    /// Ranges that only touch at a boundary do not overlap.
+    pub fn overlaps(&self, other: &TimeRange) -> bool {
+        self.start < other.end && other.start < self.end
+    }
+
+    /// Returns `true` if `other` lies entirely within this range
+    pub fn contains_range(&self, other: &TimeRange) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+}
+
+impl From<(NaiveDateTime, NaiveDateTime)> for TimeRange {
+    fn from((start, end): (NaiveDateTime, NaiveDateTime)) -> Self {
+        TimeRange::new(start, end)
+    }
+}
+
+impl From<TimeRange> for (NaiveDateTime, NaiveDateTime) {
+    fn from(range: TimeRange) -> Self {
+        (range.start, range.end)
+    }
+}
+
+impl NaiveDatetimeIterator {
+    /// Returns the `start` and `end` given at construction as a [`TimeRange`]
+    ///
+    /// The bounds are unaffected by how far the iterator has advanced. Note
+    /// that the iterator itself yields `end`, while `TimeRange` excludes it.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono_kit::iter::{NaiveDatetimeIterator, TimeRange};
+    /// use chrono::{NaiveDateTime, Duration};
+    ///
+    /// let start = NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let end = NaiveDateTime::parse_from_str("2023-01-02 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    ///
+    /// let iter = NaiveDatetimeIterator::new(start, end, Duration::hours(-1)).unwrap();
+    /// assert_eq!(iter.bounds(), TimeRange::new(start, end));
+    /// ```
+    pub fn bounds(&self) -> TimeRange {
+        self.original_bounds().into()
+    }
+}
+
+impl NaiveDatetimeRangeIterator {
+    /// Yields each range as a [`TimeRange`] instead of a tuple
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono_kit::iter::{NaiveDatetimeRangeIterator, TimeRange};
+    /// use chrono::{NaiveDateTime, Duration};
+    ///
+    /// let start = NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let end = NaiveDateTime::parse_from_str("2023-01-03 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let step = Duration::days(1);
+    ///
+    /// let mut iter = NaiveDatetimeRangeIterator::new(start, end, step).unwrap().as_structs();
+    /// assert_eq!(iter.next(), Some(TimeRange::new(start, start + step)));
+    /// ```
+    pub fn as_structs(self) -> impl Iterator<Item = TimeRange> {
+        self.map(TimeRange::from)
+    }
+
+    /// Yields only the ranges that overlap `window`
+    ///
+    /// Ranges that merely touch `window` at a boundary are skipped.
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono_kit::iter::{NaiveDatetimeRangeIterator, TimeRange};
+    /// use chrono::{NaiveDateTime, Duration};
+    ///
+    /// let start = NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let end = NaiveDateTime::parse_from_str("2023-01-01 04:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let step = Duration::hours(1);
+    /// let window = TimeRange::new(start + Duration::minutes(90), start + Duration::minutes(150));
+    ///
+    /// let iter = NaiveDatetimeRangeIterator::new(start, end, step).unwrap();
+    /// assert_eq!(iter.overlapping(window).count(), 2);
+    /// ```
+    pub fn overlapping(self, window: TimeRange) -> impl Iterator<Item = TimeRange> {
+        self.as_structs()
+            .filter(move |range| range.overlaps(&window))
+    }
+
+    /// Yields only the ranges lying entirely within `window`
+    ///
+    /// # Examples
+    /// ```
+    /// use chrono_kit::iter::{NaiveDatetimeRangeIterator, TimeRange};
+    /// use chrono::{NaiveDateTime, Duration};
+    ///
+    /// let start = NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let end = NaiveDateTime::parse_from_str("2023-01-01 04:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+    /// let step = Duration::hours(1);
+    /// let window = TimeRange::new(start + Duration::minutes(30), start + Duration::hours(3));
+    ///
+    /// let iter = NaiveDatetimeRangeIterator::new(start, end, step).unwrap();
+    /// assert_eq!(iter.contained_in(window).count(), 2);
+    /// ```
+    pub fn contained_in(self, window: TimeRange) -> impl Iterator<Item = TimeRange> {
+        self.as_structs()
+            .filter(move |range| window.contains_range(range))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dt(s: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    #[test]
+    fn test_duration() {
+        let range = TimeRange::new(dt("2023-01-01 00:00:00"), dt("2023-01-01 01:30:00"));
+        assert_eq!(range.duration(), Duration::minutes(90));
+    }
+
+    #[test]
+    fn test_contains() {
+        let range = TimeRange::new(dt("2023-01-01 00:00:00"), dt("2023-01-01 01:00:00"));
+        assert!(range.contains(dt("2023-01-01 00:00:00")));
+        assert!(range.contains(dt("2023-01-01 00:59:59")));
+        assert!(!range.contains(dt("2023-01-01 01:00:00")));
+        assert!(!range.contains(dt("2022-12-31 23:59:59")));
+    }
+
+    #[test]
+    fn test_overlaps() {
+        let range = TimeRange::new(dt("2023-01-01 00:00:00"), dt("2023-01-01 02:00:00"));
+        let partial = TimeRange::new(dt("2023-01-01 01:00:00"), dt("2023-01-01 03:00:00"));
+        let inner = TimeRange::new(dt("2023-01-01 00:30:00"), dt("2023-01-01 01:00:00"));
+        let touching = TimeRange::new(dt("2023-01-01 02:00:00"), dt("2023-01-01 03:00:00"));
+
+        assert!(range.overlaps(&partial));
+        assert!(partial.overlaps(&range));
+        assert!(range.overlaps(&inner));
+        assert!(!range.overlaps(&touching));
+    }
+
+    #[test]
+    fn test_contains_range() {
+        let range = TimeRange::new(dt("2023-01-01 00:00:00"), dt("2023-01-01 02:00:00"));
+        assert!(range.contains_range(&range));
+        assert!(range.contains_range(&TimeRange::new(
+            dt("2023-01-01 00:30:00"),
+            dt("2023-01-01 01:00:00")
+        )));
+        assert!(!range.contains_range(&TimeRange::new(
+            dt("2023-01-01 01:00:00"),
+            dt("2023-01-01 03:00:00")
+        )));
+    }
+
+    #[test]
+    fn test_overlapping_vs_contained_in() {
+        let ranges = || {
+            NaiveDatetimeRangeIterator::new(
+                dt("2023-01-01 00:00:00"),
+                dt("2023-01-01 06:00:00"),
+                Duration::hours(2),
+            )
+            .unwrap()
+        };
+        let window = TimeRange::new(dt("2023-01-01 01:00:00"), dt("2023-01-01 04:00:00"));
+
+        let overlapping: Vec<_> = ranges().overlapping(window).collect();
+        assert_eq!(
+            overlapping,
+            vec![
+                TimeRange::new(dt("2023-01-01 00:00:00"), dt("2023-01-01 02:00:00")),
+                TimeRange::new(dt("2023-01-01 02:00:00"), dt("2023-01-01 04:00:00")),
+            ]
+        );
+
+        let contained: Vec<_> = ranges().contained_in(window).collect();
+        assert_eq!(
+            contained,
+            vec![TimeRange::new(
+                dt("2023-01-01 02:00:00"),
+                dt("2023-01-01 04:00:00")
+            )]
+        );
+    }
+
+    #[test]
+    fn test_as_structs() {
+        let iter = NaiveDatetimeRangeIterator::new(
+            dt("2023-01-01 00:00:00"),
+            dt("2023-01-01 03:00:00"),
+            Duration::hours(2),
+        )
+        .unwrap();
+
+        let ranges: Vec<_> = iter.as_structs().collect();
+        assert_eq!(
+            ranges,
+            vec![
+                TimeRange::new(dt("2023-01-01 00:00:00"), dt("2023-01-01 02:00:00")),
+                TimeRange::new(dt("2023-01-01 02:00:00"), dt("2023-01-01 03:00:00")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_bounds_ignore_progress() {
+        let start = dt("2023-01-01 00:00:00");
+        let end = dt("2023-01-01 05:00:00");
+
+        let mut iter = NaiveDatetimeIterator::new(start, end, Duration::hours(2)).unwrap();
+        assert_eq!(iter.bounds(), TimeRange::new(start, end));
+        iter.nth(1);
+        assert_eq!(iter.bounds(), TimeRange::new(start, end));
+        iter.by_ref().for_each(drop);
+        assert_eq!(iter.bounds(), TimeRange::new(start, end));
+    }
+}
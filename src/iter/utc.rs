@@ -0,0 +1,63 @@
+use chrono::{DateTime, Duration, Utc};
+
+/// Returns `n` UTC timestamps spaced `step` apart, starting at `start`
+///
+/// The step may be negative for a descending series. Stepping stops early
+/// instead of overflowing, so fewer than `n` timestamps are returned if the
+/// series would leave the range `DateTime<Utc>` can represent.
+///
+/// # Examples
+/// ```
+/// use chrono_kit::iter::utc_series;
+/// use chrono::{DateTime, Duration, Utc};
+///
+/// let start = DateTime::parse_from_rfc3339("2023-01-01T00:00:00Z").unwrap().with_timezone(&Utc);
+///
+/// let series = utc_series(start, Duration::minutes(15), 3);
+/// assert_eq!(series, vec![start, start + Duration::minutes(15), start + Duration::minutes(30)]);
+/// ```
+pub fn utc_series(start: DateTime<Utc>, step: Duration, n: usize) -> Vec<DateTime<Utc>> {
+    std::iter::successors(Some(start), |dt| dt.checked_add_signed(step))
+        .take(n)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+    }
+
+    #[test]
+    fn test_empty_and_single() {
+        let start = utc("2023-01-01T00:00:00Z");
+
+        assert!(utc_series(start, Duration::hours(1), 0).is_empty());
+        assert_eq!(utc_series(start, Duration::hours(1), 1), vec![start]);
+    }
+
+    #[test]
+    fn test_several() {
+        let start = utc("2023-03-26T00:30:00+01:00");
+        let series = utc_series(start, Duration::hours(1), 4);
+
+        assert_eq!(series.len(), 4);
+        assert_eq!(series[0].to_rfc3339(), "2023-03-25T23:30:00+00:00");
+        assert_eq!(series[3], utc("2023-03-26T02:30:00Z"));
+        assert!(series.windows(2).all(|w| w[1] - w[0] == Duration::hours(1)));
+
+        let series = utc_series(start, Duration::days(-1), 3);
+        assert_eq!(series[2], utc("2023-03-23T23:30:00Z"));
+    }
+
+    #[test]
+    fn test_stops_before_overflow() {
+        let start = DateTime::<Utc>::MAX_UTC - Duration::days(1);
+
+        let series = utc_series(start, Duration::hours(12), 5);
+        assert_eq!(series.len(), 3);
+        assert_eq!(series[2], DateTime::<Utc>::MAX_UTC);
+    }
+}
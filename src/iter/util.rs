@@ -0,0 +1,30 @@
+use chrono::{DateTime, Duration, NaiveDateTime};
+
+const NANOS_PER_SEC: i128 = 1_000_000_000;
+
+/// The origin shared by all epoch-anchored grids
+pub(crate) fn epoch() -> NaiveDateTime {
+    DateTime::UNIX_EPOCH.naive_utc()
+}
+
+/// Converts a duration into a signed count of nanoseconds without overflow
+pub(crate) fn duration_to_nanos(duration: Duration) -> i128 {
+    duration.num_seconds() as i128 * NANOS_PER_SEC + duration.subsec_nanos() as i128
+}
+
+/// Converts a signed count of nanoseconds back into a duration
+///
+/// Returns `None` if the value is outside the range `Duration` can represent.
+pub(crate) fn nanos_to_duration(nanos: i128) -> Option<Duration> {
+    let secs = i64::try_from(nanos.div_euclid(NANOS_PER_SEC)).ok()?;
+    Duration::new(secs, nanos.rem_euclid(NANOS_PER_SEC) as u32)
+}
+
+/// Advances a SplitMix64 state and returns the next pseudo-random value
+pub(crate) fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
@@ -0,0 +1,178 @@
+use super::naive_datetime_iter::NaiveDatetimeIterError;
+use chrono::{Duration, NaiveDateTime};
+
+/// Iterator that yields datetimes following a repeating pattern of steps
+///
+/// Each step in the pattern is applied in turn, wrapping back to the first
+/// one after the last. By default the final value is clamped to `end` like
+/// [`NaiveDatetimeIterator`](crate::iter::NaiveDatetimeIterator); use
+/// [`without_clamping`](Self::without_clamping) to stop at the last value that
+/// lands on the pattern instead.
+///
+/// # Examples
+/// ```
+/// use chrono_kit::iter::VariableStepIterator;
+/// use chrono::{NaiveDateTime, Duration};
+///
+/// let start = NaiveDateTime::parse_from_str("2023-01-01 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+/// let end = NaiveDateTime::parse_from_str("2023-01-01 06:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+/// let steps = vec![Duration::hours(1), Duration::hours(2)];
+///
+/// let mut iter = VariableStepIterator::new(start, end, steps).unwrap();
+/// assert_eq!(iter.next(), Some(start));
+/// assert_eq!(iter.next(), Some(start + Duration::hours(1)));
+/// assert_eq!(iter.next(), Some(start + Duration::hours(3)));
+/// assert_eq!(iter.next(), Some(start + Duration::hours(4)));
+/// assert_eq!(iter.next(), Some(end));
+/// assert_eq!(iter.next(), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct VariableStepIterator {
+    next: Option<NaiveDateTime>,
+    end: NaiveDateTime,
+    steps: Vec<Duration>,
+    index: usize,
+    clamp: bool,
+}
+
+impl VariableStepIterator {
+    /// Creates a new VariableStepIterator
+    ///
+    /// # Arguments
+    /// * `start` - The starting datetime (inclusive)
+    /// * `end` - The ending datetime (inclusive)
+    /// * `steps` - The repeating pattern of durations between values
+    ///
+    /// # Errors
+    /// Returns `DatetimeIterError` if:
+    /// - `steps` is empty or contains a zero or negative duration
+    /// - `start` is after `end`
+    pub fn new(
+        start: NaiveDateTime,
+        end: NaiveDateTime,
+        steps: Vec<Duration>,
+    ) -> Result<Self, NaiveDatetimeIterError> {
+        if steps.is_empty() || steps.iter().any(|step| *step <= Duration::zero()) {
+            return Err(NaiveDatetimeIterError::InvalidStepPattern);
+        }
+        if start > end {
+            return Err(NaiveDatetimeIterError::InvalidRange { start, end });
+        }
+        Ok(VariableStepIterator {
+            next: Some(start),
+            end,
+            steps,
+            index: 0,
+            clamp: true,
+        })
+    }
+
+    /// Disables clamping so that `end` is only yielded if the pattern lands on it
+    pub fn without_clamping(mut self) -> Self {
+        self.clamp = false;
+        self
+    }
+}
+
+impl Iterator for VariableStepIterator {
+    type Item = NaiveDateTime;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let result = self.next?;
+
+        let step = self.steps[self.index];
+        self.index = (self.index + 1) % self.steps.len();
+        self.next = match result.checked_add_signed(step) {
+            Some(next) if next <= self.end => Some(next),
+            _ if self.clamp && result < self.end => Some(self.end),
+            _ => None,
+        };
+
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dt(s: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    #[test]
+    fn test_pattern_wraps_around() {
+        let steps = vec![Duration::hours(1), Duration::hours(2), Duration::hours(4)];
+        let iter =
+            VariableStepIterator::new(dt("2023-01-01 00:00:00"), dt("2023-01-01 14:00:00"), steps)
+                .unwrap();
+
+        let points: Vec<_> = iter.collect();
+        assert_eq!(
+            points,
+            vec![
+                dt("2023-01-01 00:00:00"),
+                dt("2023-01-01 01:00:00"),
+                dt("2023-01-01 03:00:00"),
+                dt("2023-01-01 07:00:00"),
+                dt("2023-01-01 08:00:00"),
+                dt("2023-01-01 10:00:00"),
+                dt("2023-01-01 14:00:00"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_clamped_tail() {
+        let steps = vec![Duration::hours(1), Duration::hours(2), Duration::hours(4)];
+        let iter =
+            VariableStepIterator::new(dt("2023-01-01 00:00:00"), dt("2023-01-01 05:00:00"), steps)
+                .unwrap();
+
+        let points: Vec<_> = iter.collect();
+        assert_eq!(
+            points,
+            vec![
+                dt("2023-01-01 00:00:00"),
+                dt("2023-01-01 01:00:00"),
+                dt("2023-01-01 03:00:00"),
+                dt("2023-01-01 05:00:00"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_without_clamping() {
+        let steps = vec![Duration::hours(1), Duration::hours(2), Duration::hours(4)];
+        let iter =
+            VariableStepIterator::new(dt("2023-01-01 00:00:00"), dt("2023-01-01 05:00:00"), steps)
+                .unwrap()
+                .without_clamping();
+
+        assert_eq!(iter.last(), Some(dt("2023-01-01 03:00:00")));
+    }
+
+    #[test]
+    fn test_invalid_patterns() {
+        let start = dt("2023-01-01 00:00:00");
+        let end = dt("2023-01-01 05:00:00");
+
+        for steps in [
+            vec![],
+            vec![Duration::hours(1), Duration::zero()],
+            vec![Duration::hours(-1)],
+        ] {
+            let result = VariableStepIterator::new(start, end, steps);
+            assert!(matches!(
+                result,
+                Err(NaiveDatetimeIterError::InvalidStepPattern)
+            ));
+        }
+
+        let result = VariableStepIterator::new(end, start, vec![Duration::hours(1)]);
+        assert!(matches!(
+            result,
+            Err(NaiveDatetimeIterError::InvalidRange { .. })
+        ));
+    }
+}
@@ -0,0 +1,414 @@
+use super::naive_datetime_iter::{NaiveDatetimeIterError, NaiveDatetimeIterator};
+use chrono::{DateTime, Duration, TimeZone};
+#[cfg(feature = "chrono-tz")]
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+
+/// Iterator that yields timezone-aware datetimes between start and end with given step
+///
+/// Steps are measured in elapsed time rather than on the local wall clock:
+/// each datetime is computed in UTC and then converted into the time zone of
+/// `start`. This means a local time that does not exist is never yielded, and
+/// an ambiguous local time is yielded with the offset it actually had. For
+/// example, stepping hourly in `America/New_York`:
+/// - across the spring-forward transition, `01:00-05:00` is followed by
+///   `03:00-04:00`, skipping the nonexistent `02:00`
+/// - across the fall-back transition, `01:00-04:00` is followed by
+///   `01:00-05:00`, yielding the repeated hour in both folds
+///
+/// Otherwise it behaves like [`NaiveDatetimeIterator`]: the step sign selects
+/// the direction, and the far bound is always yielded last.
+///
+/// # Examples
+/// ```
+/// use chrono_kit::iter::DateTimeIterator;
+/// use chrono::{DateTime, Duration};
+///
+/// let start = DateTime::parse_from_rfc3339("2023-01-01T00:00:00+02:00").unwrap();
+/// let end = DateTime::parse_from_rfc3339("2023-01-01T01:00:00+02:00").unwrap();
+///
+/// let mut iter = DateTimeIterator::new(start, end, Duration::minutes(30)).unwrap();
+/// assert_eq!(iter.next(), Some(start));
+/// assert_eq!(iter.next(), Some(start + Duration::minutes(30)));
+/// assert_eq!(iter.next(), Some(end));
+/// assert_eq!(iter.next(), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct DateTimeIterator<Tz: TimeZone> {
+    tz: Tz,
+    utc_iter: NaiveDatetimeIterator,
+}
+
+impl<Tz: TimeZone> DateTimeIterator<Tz> {
+    /// Creates a new DateTimeIterator
+    ///
+    /// # Arguments
+    /// * `start` - The starting datetime (inclusive), whose time zone is used for every item
+    /// * `end` - The ending datetime (inclusive)
+    /// * `step` - The elapsed duration between each step (must be non-zero)
+    ///
+    /// # Errors
+    /// Returns `NaiveDatetimeIterError` if:
+    /// - `step` is zero
+    /// - `start` is after `end`, reported with both bounds in UTC
+    /// - the grid has more datetimes than fit in a `usize`
+    pub fn new(
+        start: DateTime<Tz>,
+        end: DateTime<Tz>,
+        step: Duration,
+    ) -> Result<Self, NaiveDatetimeIterError> {
+        let utc_iter = NaiveDatetimeIterator::new(start.naive_utc(), end.naive_utc(), step)?;
+        Ok(DateTimeIterator {
+            tz: start.timezone(),
+            utc_iter,
+        })
+    }
+}
+
+impl<Tz: TimeZone> Iterator for DateTimeIterator<Tz> {
+    type Item = DateTime<Tz>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.utc_iter
+            .next()
+            .map(|dt| self.tz.from_utc_datetime(&dt))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.utc_iter.size_hint()
+    }
+}
+
+impl<Tz: TimeZone> DoubleEndedIterator for DateTimeIterator<Tz> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.utc_iter
+            .next_back()
+            .map(|dt| self.tz.from_utc_datetime(&dt))
+    }
+}
+
+impl<Tz: TimeZone> ExactSizeIterator for DateTimeIterator<Tz> {}
+
+/// Resolves a wall-clock datetime in `tz` to a single instant
+///
+/// Ambiguous times take the earlier instant, and times skipped by a DST
+/// change are moved forward by an hour.
+#[cfg(feature = "chrono-tz")]
+fn localize(tz: chrono_tz::Tz, dt: NaiveDateTime) -> Option<DateTime<chrono_tz::Tz>> {
+    tz.from_local_datetime(&dt).earliest().or_else(|| {
+        tz.from_local_datetime(&(dt + Duration::hours(1)))
+            .earliest()
+    })
+}
+
+/// Returns the working window `hours` on `date` in `tz` as instants
+#[cfg(feature = "chrono-tz")]
+fn working_window(
+    tz: chrono_tz::Tz,
+    (open, close): (NaiveTime, NaiveTime),
+    date: NaiveDate,
+) -> Option<(DateTime<chrono_tz::Tz>, DateTime<chrono_tz::Tz>)> {
+    let close_date = if close <= open {
+        date.succ_opt()?
+    } else {
+        date
+    };
+    Some((
+        localize(tz, date.and_time(open))?,
+        localize(tz, close_date.and_time(close))?,
+    ))
+}
+
+/// Returns the overlap of two time zones' working hours on a date
+///
+/// Each zone's window runs from its opening to its closing time on `date`
+/// in that zone's own wall clock; a closing time not after the opening time
+/// falls on the following day, as with [`ShiftIterator`](crate::iter::ShiftIterator).
+/// The windows are intersected as instants, and the overlap is returned as
+/// wall-clock datetimes in `tz_a`, which may fall on a different date than
+/// `date`.
+///
+/// Returns `None` if the windows do not overlap or only touch. Ambiguous
+/// times take the earlier instant, and times skipped by a DST change are
+/// moved forward by an hour.
+///
+/// # Examples
+/// ```
+/// use chrono_kit::iter::overlap_hours;
+/// use chrono::{NaiveDate, NaiveTime};
+/// use chrono_tz::{Europe::London, America::New_York};
+///
+/// let date = NaiveDate::from_ymd_opt(2023, 6, 1).unwrap();
+/// let nine_to_five = (NaiveTime::from_hms_opt(9, 0, 0).unwrap(), NaiveTime::from_hms_opt(17, 0, 0).unwrap());
+///
+/// let (start, end) = overlap_hours(London, New_York, nine_to_five, nine_to_five, date).unwrap();
+/// assert_eq!(start, date.and_hms_opt(14, 0, 0).unwrap());
+/// assert_eq!(end, date.and_hms_opt(17, 0, 0).unwrap());
+/// ```
+#[cfg(feature = "chrono-tz")]
+pub fn overlap_hours(
+    tz_a: chrono_tz::Tz,
+    tz_b: chrono_tz::Tz,
+    hours_a: (NaiveTime, NaiveTime),
+    hours_b: (NaiveTime, NaiveTime),
+    date: NaiveDate,
+) -> Option<(NaiveDateTime, NaiveDateTime)> {
+    let (open_a, close_a) = working_window(tz_a, hours_a, date)?;
+    let (open_b, close_b) = working_window(tz_b, hours_b, date)?;
+
+    let open = open_a.max(open_b.with_timezone(&tz_a));
+    let close = close_a.min(close_b.with_timezone(&tz_a));
+    (open < close).then(|| (open.naive_local(), close.naive_local()))
+}
+
+/// How a naive datetime fails to map to a single instant in a time zone
+#[cfg(feature = "chrono-tz")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Anomaly {
+    /// The wall-clock time occurs twice, when clocks fall back
+    Ambiguous,
+    /// The wall-clock time is skipped, when clocks spring forward
+    Nonexistent,
+}
+
+/// Reports the datetimes of a naive grid that are ambiguous or nonexistent in `tz`
+///
+/// Every datetime is read as a wall-clock time in `tz`; those that map to
+/// exactly one instant are left out of the result.
+///
+/// # Examples
+/// ```
+/// use chrono_kit::iter::{dst_anomalies, Anomaly, NaiveDatetimeIterator};
+/// use chrono::{NaiveDateTime, Duration};
+/// use chrono_tz::America::New_York;
+///
+/// let start = NaiveDateTime::parse_from_str("2023-03-12 00:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+/// let end = NaiveDateTime::parse_from_str("2023-03-12 04:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+///
+/// let iter = NaiveDatetimeIterator::new(start, end, Duration::hours(1)).unwrap();
+/// assert_eq!(
+///     dst_anomalies(iter, New_York),
+///     vec![(start + Duration::hours(2), Anomaly::Nonexistent)]
+/// );
+/// ```
+#[cfg(feature = "chrono-tz")]
+pub fn dst_anomalies(
+    iter: NaiveDatetimeIterator,
+    tz: chrono_tz::Tz,
+) -> Vec<(NaiveDateTime, Anomaly)> {
+    use chrono::LocalResult;
+
+    iter.filter_map(|dt| match tz.from_local_datetime(&dt) {
+        LocalResult::Single(_) => None,
+        LocalResult::Ambiguous(..) => Some((dt, Anomaly::Ambiguous)),
+        LocalResult::None => Some((dt, Anomaly::Nonexistent)),
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{FixedOffset, Utc};
+
+    #[test]
+    fn test_fixed_offset_iteration() {
+        let start = DateTime::parse_from_rfc3339("2023-01-01T00:00:00+05:30").unwrap();
+        let end = DateTime::parse_from_rfc3339("2023-01-01T01:00:00+05:30").unwrap();
+
+        let times: Vec<_> = DateTimeIterator::new(start, end, Duration::minutes(-25))
+            .unwrap()
+            .map(|dt| dt.to_rfc3339())
+            .collect();
+        assert_eq!(
+            times,
+            vec![
+                "2023-01-01T01:00:00+05:30",
+                "2023-01-01T00:35:00+05:30",
+                "2023-01-01T00:10:00+05:30",
+                "2023-01-01T00:00:00+05:30",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_items_use_start_time_zone() {
+        let start = DateTime::parse_from_rfc3339("2023-01-01T00:00:00+01:00").unwrap();
+        let end = DateTime::parse_from_rfc3339("2023-01-01T00:00:00+00:00").unwrap();
+
+        let times: Vec<_> = DateTimeIterator::new(start, end, Duration::minutes(30))
+            .unwrap()
+            .collect();
+        assert_eq!(times.len(), 3);
+        assert!(
+            times
+                .iter()
+                .all(|dt| dt.offset() == &FixedOffset::east_opt(3600).unwrap())
+        );
+        assert_eq!(times[2], end);
+    }
+
+    #[test]
+    fn test_errors() {
+        let start = DateTime::parse_from_rfc3339("2023-01-01T00:00:00Z").unwrap();
+        let end = start.with_timezone(&Utc) + Duration::hours(1);
+
+        assert!(matches!(
+            DateTimeIterator::new(start.with_timezone(&Utc), end, Duration::zero()),
+            Err(NaiveDatetimeIterError::ZeroStep)
+        ));
+        assert!(matches!(
+            DateTimeIterator::new(end, start.with_timezone(&Utc), Duration::hours(1)),
+            Err(NaiveDatetimeIterError::InvalidRange { .. })
+        ));
+    }
+
+    #[cfg(feature = "chrono-tz")]
+    #[test]
+    fn test_spring_forward_skips_missing_hour() {
+        use chrono_tz::America::New_York;
+
+        let start = New_York.with_ymd_and_hms(2023, 3, 12, 0, 0, 0).unwrap();
+        let end = New_York.with_ymd_and_hms(2023, 3, 12, 4, 0, 0).unwrap();
+
+        let times: Vec<_> = DateTimeIterator::new(start, end, Duration::hours(1))
+            .unwrap()
+            .map(|dt| dt.to_rfc3339())
+            .collect();
+        assert_eq!(
+            times,
+            vec![
+                "2023-03-12T00:00:00-05:00",
+                "2023-03-12T01:00:00-05:00",
+                "2023-03-12T03:00:00-04:00",
+                "2023-03-12T04:00:00-04:00",
+            ]
+        );
+    }
+
+    #[cfg(feature = "chrono-tz")]
+    #[test]
+    fn test_fall_back_repeats_hour_in_both_folds() {
+        use chrono_tz::America::New_York;
+
+        let start = New_York.with_ymd_and_hms(2023, 11, 5, 0, 0, 0).unwrap();
+        let end = New_York.with_ymd_and_hms(2023, 11, 5, 2, 0, 0).unwrap();
+
+        let times: Vec<_> = DateTimeIterator::new(start, end, Duration::hours(1))
+            .unwrap()
+            .map(|dt| dt.to_rfc3339())
+            .collect();
+        assert_eq!(
+            times,
+            vec![
+                "2023-11-05T00:00:00-04:00",
+                "2023-11-05T01:00:00-04:00",
+                "2023-11-05T01:00:00-05:00",
+                "2023-11-05T02:00:00-05:00",
+            ]
+        );
+    }
+
+    #[cfg(feature = "chrono-tz")]
+    fn hours(open: u32, close: u32) -> (chrono::NaiveTime, chrono::NaiveTime) {
+        (
+            chrono::NaiveTime::from_hms_opt(open, 0, 0).unwrap(),
+            chrono::NaiveTime::from_hms_opt(close, 0, 0).unwrap(),
+        )
+    }
+
+    #[cfg(feature = "chrono-tz")]
+    #[test]
+    fn test_overlap_hours_overlapping() {
+        use chrono::NaiveDate;
+        use chrono_tz::{America::New_York, Europe::Berlin};
+
+        let date = NaiveDate::from_ymd_opt(2023, 1, 10).unwrap();
+        let at = |h| date.and_hms_opt(h, 0, 0).unwrap();
+
+        // Berlin is six hours ahead of New York in winter
+        assert_eq!(
+            overlap_hours(Berlin, New_York, hours(9, 18), hours(9, 17), date),
+            Some((at(15), at(18)))
+        );
+        assert_eq!(
+            overlap_hours(New_York, Berlin, hours(9, 17), hours(9, 18), date),
+            Some((at(9), at(12)))
+        );
+    }
+
+    #[cfg(feature = "chrono-tz")]
+    #[test]
+    fn test_overlap_hours_disjoint() {
+        use chrono::NaiveDate;
+        use chrono_tz::{America::Los_Angeles, Asia::Kolkata};
+
+        let date = NaiveDate::from_ymd_opt(2023, 1, 10).unwrap();
+        assert_eq!(
+            overlap_hours(Kolkata, Los_Angeles, hours(9, 17), hours(9, 17), date),
+            None
+        );
+    }
+
+    #[cfg(feature = "chrono-tz")]
+    #[test]
+    fn test_overlap_hours_overnight_window() {
+        use chrono::NaiveDate;
+        use chrono_tz::{America::New_York, Europe::London};
+
+        let date = NaiveDate::from_ymd_opt(2023, 1, 10).unwrap();
+        let next = date.succ_opt().unwrap();
+
+        // A London night shift overlaps the New York evening into the next day
+        assert_eq!(
+            overlap_hours(London, New_York, hours(22, 6), hours(18, 23), date),
+            Some((
+                date.and_hms_opt(23, 0, 0).unwrap(),
+                next.and_hms_opt(4, 0, 0).unwrap()
+            ))
+        );
+    }
+
+    #[cfg(feature = "chrono-tz")]
+    #[test]
+    fn test_dst_anomalies_transition_days() {
+        use chrono_tz::America::New_York;
+
+        let dt = |s| chrono::NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").unwrap();
+
+        let spring = NaiveDatetimeIterator::new(
+            dt("2023-03-12 00:00:00"),
+            dt("2023-03-13 00:00:00"),
+            Duration::minutes(30),
+        )
+        .unwrap();
+        assert_eq!(
+            dst_anomalies(spring, New_York),
+            vec![
+                (dt("2023-03-12 02:00:00"), Anomaly::Nonexistent),
+                (dt("2023-03-12 02:30:00"), Anomaly::Nonexistent),
+            ]
+        );
+
+        let fall = NaiveDatetimeIterator::new(
+            dt("2023-11-05 00:00:00"),
+            dt("2023-11-06 00:00:00"),
+            Duration::minutes(-30),
+        )
+        .unwrap();
+        assert_eq!(
+            dst_anomalies(fall, New_York),
+            vec![
+                (dt("2023-11-05 01:30:00"), Anomaly::Ambiguous),
+                (dt("2023-11-05 01:00:00"), Anomaly::Ambiguous),
+            ]
+        );
+
+        let ordinary = NaiveDatetimeIterator::new(
+            dt("2023-06-01 00:00:00"),
+            dt("2023-06-02 00:00:00"),
+            Duration::minutes(30),
+        )
+        .unwrap();
+        assert!(dst_anomalies(ordinary, New_York).is_empty());
+    }
+}